@@ -2,6 +2,7 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
 
 use iroha_crypto::{HashOf, PublicKey};
 use iroha_primitives::{json::Json, numeric::Numeric};
@@ -36,7 +37,7 @@ use crate::{
         CommittedTransaction,
     },
     role::{Role, RoleId},
-    transaction::{TransactionEntrypoint, TransactionResult},
+    transaction::{error::RejectionReasonKind, TransactionEntrypoint, TransactionResult},
     trigger::{action, Trigger, TriggerId},
 };
 
@@ -56,7 +57,7 @@ macro_rules! impl_predicate_atom {
             $atom_name:ident($input_name:ident: $ty_name:ty) [$prototype_name:ident] {
                 $(
                     $(#[$($variant_attrs:tt)*])*
-                    $variant_name:ident$(($variant_pat:ident: $variant_ty:ty))? [$constructor_name:ident] => $variant_expr:expr
+                    $variant_name:ident$(($($variant_pat:ident: $variant_ty:ty),+ $(,)?))? [$constructor_name:ident] => $variant_expr:expr
                 ),*
                 $(,)?
             }
@@ -75,7 +76,7 @@ macro_rules! impl_predicate_atom {
             pub enum $atom_name {
                 $(
                     $(#[$($variant_attrs)*])*
-                    $variant_name$(($variant_ty))?,
+                    $variant_name$(($($variant_ty),+))?,
                 )*
             }
 
@@ -88,7 +89,7 @@ macro_rules! impl_predicate_atom {
             impl_predicate_atom!{ @impl_evaluate_for_all_types $atom_name $input_name ($ty_name)
                 // can't use `self` directly because of the macro hygiene, hence using a closure instead
                 |this: &$atom_name| match *this {
-                    $($atom_name::$variant_name$((ref $variant_pat))? => $variant_expr,)*
+                    $($atom_name::$variant_name$(($(ref $variant_pat),+))? => $variant_expr,)*
                 }
             }
 
@@ -99,9 +100,9 @@ macro_rules! impl_predicate_atom {
             {
                 $(
                     $(#[$($variant_attrs)*])*
-                    pub fn $constructor_name(self $(, $variant_pat: $variant_ty)?) -> CompoundPredicate<Projector::OutputType> {
+                    pub fn $constructor_name(self $(, $($variant_pat: $variant_ty),+)?) -> CompoundPredicate<Projector::OutputType> {
                         CompoundPredicate::Atom(self.projector.wrap_atom(
-                            $atom_name::$variant_name$(($variant_pat))?
+                            $atom_name::$variant_name$(($($variant_pat),+))?
                         ))
                     }
                 )*
@@ -256,8 +257,19 @@ where
 }
 
 impl_predicate_atom! {
-    MetadataPredicateAtom(_input: Metadata) [MetadataPrototype] {
-        // TODO: populate
+    MetadataPredicateAtom(input: Metadata) [MetadataPrototype] {
+        /// Checks if the metadata has an entry for the given key.
+        HasKey(key: Name) [has_key] => input.get(key).is_some(),
+        /// Checks if the metadata has an entry for the given key equal to the expected value.
+        KeyEquals(key: Name, expected: Json) [key_eq] => input.get(key) == Some(expected),
+        /// Checks if the value at `key`, after walking `pointer` as an RFC-6901 JSON Pointer
+        /// into it, satisfies the nested predicate.
+        KeyAtPath(key: Name, pointer: String, predicate: JsonPredicateAtom) [key_at_path] => {
+            input
+                .get(key)
+                .and_then(|value| value.pointer(pointer))
+                .is_some_and(|pointed| predicate.applies(&Json::new(pointed.clone())))
+        },
     }
     PublicKeyPredicateAtom(input: PublicKey) [PublicKeyPrototype] {
         /// Checks if the input is equal to the expected value.
@@ -267,8 +279,19 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: Json) [eq] => input == expected,
     }
-    NumericPredicateAtom(_input: Numeric) [NumericPrototype] {
-        // TODO: populate
+    NumericPredicateAtom(input: Numeric) [NumericPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: Numeric) [eq] => input == expected,
+        /// Checks if the input is strictly greater than the expected value.
+        GreaterThan(expected: Numeric) [gt] => input > expected,
+        /// Checks if the input is greater than or equal to the expected value.
+        GreaterOrEqual(expected: Numeric) [ge] => input >= expected,
+        /// Checks if the input is strictly less than the expected value.
+        LessThan(expected: Numeric) [lt] => input < expected,
+        /// Checks if the input is less than or equal to the expected value.
+        LessOrEqual(expected: Numeric) [le] => input <= expected,
+        /// Checks if the input falls within `[min, max]`, inclusive of both ends.
+        InRange(min: Numeric, max: Numeric) [in_range] => input >= min && input <= max,
     }
 
     // account
@@ -302,7 +325,16 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: HashOf<BlockHeader>) [eq] => input == expected,
     }
-    BlockHeaderPredicateAtom(_input: BlockHeader) [BlockHeaderPrototype] {}
+    BlockHeaderPredicateAtom(input: BlockHeader) [BlockHeaderPrototype] {
+        /// Checks if the block's height is strictly greater than the expected value.
+        HeightGreaterThan(expected: u64) [height_gt] => input.height() > *expected,
+        /// Checks if the block's height is strictly less than the expected value.
+        HeightLessThan(expected: u64) [height_lt] => input.height() < *expected,
+        /// Checks if the block was created strictly before the expected timestamp.
+        TimestampBefore(expected: Duration) [timestamp_before] => input.creation_time() < *expected,
+        /// Checks if the block was created strictly after the expected timestamp.
+        TimestampAfter(expected: Duration) [timestamp_after] => input.creation_time() > *expected,
+    }
     SignedBlockPredicateAtom(input: SignedBlock) [SignedBlockPrototype] {
         /// Checks if the block is empty (has no transactions)
         IsEmpty [is_empty] => input.is_empty(),
@@ -323,7 +355,11 @@ impl_predicate_atom! {
         /// Returns true if the transaction succeeded.
         IsOk [is_ok] => input.is_ok(),
         /// Returns true if the transaction succeeded and the includes a data trigger with the specified ID.
-        ContainsDataTrigger(expected: TriggerId) [contains_data_trigger] => input.as_ref().is_ok_and(|sequence| sequence.iter().any(|step| step.id == *expected)),
+        ContainsDataTrigger(expected: TriggerId) [contains_data_trigger] => input.as_ref().is_ok_and(|sequence| sequence.iter().flat_map(|group| group.iter()).any(|step| step.id == *expected)),
+        /// Returns true if the transaction failed.
+        IsErr [is_err] => input.is_err(),
+        /// Returns true if the transaction failed with a rejection reason of the given kind.
+        ErrorMatches(kind: RejectionReasonKind) [error_matches] => input.as_ref().err().is_some_and(|reason| reason.kind() == *kind),
     }
     CommittedTransactionPredicateAtom(_input: CommittedTransaction) [CommittedTransactionPrototype] {}
 
@@ -359,6 +395,792 @@ impl_predicate_atom! {
     ActionPredicateAtom(_input: action::Action) [ActionPrototype] {}
 }
 
+/// Builder returned by [`MetadataPrototype::key`], narrowing subsequent predicates to the value
+/// stored under a particular key.
+pub struct MetadataKeyPrototype<Projector> {
+    projector: Projector,
+    key: Name,
+}
+
+impl<Projector> MetadataKeyPrototype<Projector>
+where
+    Projector: ObjectProjector<PredicateMarker, InputType = Metadata>,
+{
+    /// Narrow further to the value found by walking `pointer`, an RFC-6901 JSON Pointer, inside
+    /// the value stored at this key.
+    pub fn at(self, pointer: impl Into<String>) -> MetadataKeyPathPrototype<Projector> {
+        MetadataKeyPathPrototype {
+            projector: self.projector,
+            key: self.key,
+            pointer: pointer.into(),
+        }
+    }
+}
+
+/// Sub-prototype returned by [`MetadataKeyPrototype::at`], composing a [`JsonPredicateAtom`]
+/// against the value found at the key and pointer.
+pub struct MetadataKeyPathPrototype<Projector> {
+    projector: Projector,
+    key: Name,
+    pointer: String,
+}
+
+impl<Projector> MetadataKeyPathPrototype<Projector>
+where
+    Projector: ObjectProjector<PredicateMarker, InputType = Metadata>,
+{
+    /// Checks if the pointed-to value is equal to the expected value.
+    pub fn eq(self, expected: Json) -> CompoundPredicate<Projector::OutputType> {
+        self.into_predicate(JsonPredicateAtom::Equals(expected))
+    }
+
+    fn into_predicate(
+        self,
+        predicate: JsonPredicateAtom,
+    ) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(self.projector.wrap_atom(MetadataPredicateAtom::KeyAtPath(
+            self.key,
+            self.pointer,
+            predicate,
+        )))
+    }
+}
+
+impl<Projector> MetadataPrototype<PredicateMarker, Projector>
+where
+    Projector: ObjectProjector<PredicateMarker, InputType = Metadata>,
+{
+    /// Narrow to the value stored under `key`, chaining into [`MetadataKeyPrototype::at`] to
+    /// reach a nested value by JSON Pointer.
+    pub fn key(self, key: Name) -> MetadataKeyPrototype<Projector> {
+        MetadataKeyPrototype {
+            projector: self.projector,
+            key,
+        }
+    }
+}
+
+impl<T> CompoundPredicate<T>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    /// Produce a canonical, flattened form of this predicate: nested `And`/`Or` nodes of the
+    /// same kind collapse into a single flat list, structurally-equal children of a commutative
+    /// node are deduplicated, double negation collapses to its inner predicate, and the
+    /// children of a commutative node are sorted into a deterministic order (by their [`Debug`]
+    /// representation, since atoms aren't required to implement [`Ord`]). Two predicates that
+    /// differ only in nesting of same-kind nodes, duplicate children, child order, or double
+    /// negation therefore simplify to the same tree; other logical equivalences (e.g. De
+    /// Morgan's laws) are not recognized, and empty `And`/`Or` nodes are kept as they are. Server-side
+    /// query evaluation can key a cache on the canonical form instead of re-walking
+    /// structurally distinct but equivalent trees for every request.
+    #[must_use]
+    pub fn simplify(self) -> Self {
+        match self {
+            CompoundPredicate::Not(inner) => match inner.simplify() {
+                CompoundPredicate::Not(doubly_inner) => *doubly_inner,
+                simplified => CompoundPredicate::Not(Box::new(simplified)),
+            },
+            CompoundPredicate::And(children) => {
+                let mut flat = Vec::new();
+                flatten_compound(children, &mut flat, |child| {
+                    matches!(child, CompoundPredicate::And(_))
+                });
+                fold_compound(flat, CompoundPredicate::And)
+            }
+            CompoundPredicate::Or(children) => {
+                let mut flat = Vec::new();
+                flatten_compound(children, &mut flat, |child| {
+                    matches!(child, CompoundPredicate::Or(_))
+                });
+                fold_compound(flat, CompoundPredicate::Or)
+            }
+            atom @ CompoundPredicate::Atom(_) => atom,
+        }
+    }
+}
+
+/// Simplify each child, then gather the operands of a (possibly already-nested) commutative
+/// node into `out`, splicing in the children of any nested node `is_same_kind` reports as the
+/// same kind of node instead of keeping it as a separate nested layer.
+fn flatten_compound<T>(
+    children: Vec<CompoundPredicate<T>>,
+    out: &mut Vec<CompoundPredicate<T>>,
+    is_same_kind: impl Fn(&CompoundPredicate<T>) -> bool + Copy,
+) where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    for child in children {
+        match child.simplify() {
+            simplified if is_same_kind(&simplified) => match simplified {
+                CompoundPredicate::And(nested) | CompoundPredicate::Or(nested) => {
+                    out.extend(nested);
+                }
+                _ => unreachable!("is_same_kind only matches And/Or"),
+            },
+            simplified => out.push(simplified),
+        }
+    }
+}
+
+/// Sort and deduplicate flattened operands, then fold them back into a single node, unless only
+/// one operand is left, in which case it replaces the node entirely (`and(x) == x`).
+fn fold_compound<T>(
+    mut children: Vec<CompoundPredicate<T>>,
+    combine: fn(Vec<CompoundPredicate<T>>) -> CompoundPredicate<T>,
+) -> CompoundPredicate<T>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    children.sort_by_key(|child| format!("{child:?}"));
+    children.dedup();
+    if children.len() == 1 {
+        children
+            .into_iter()
+            .next()
+            .expect("just checked len() == 1")
+    } else {
+        combine(children)
+    }
+}
+
+#[cfg(test)]
+mod compound_predicate_tests {
+    use super::*;
+
+    fn eq(s: &str) -> CompoundPredicate<StringPredicateAtom> {
+        CompoundPredicate::Atom(StringPredicateAtom::Equals(s.to_owned()))
+    }
+
+    #[test]
+    fn simplify_flattens_nested_same_kind_nodes() {
+        let nested = CompoundPredicate::And(vec![
+            eq("a"),
+            CompoundPredicate::And(vec![eq("b"), eq("c")]),
+        ]);
+        let flat = CompoundPredicate::And(vec![eq("a"), eq("b"), eq("c")]);
+        assert_eq!(nested.simplify(), flat.simplify());
+    }
+
+    #[test]
+    fn simplify_deduplicates_structurally_equal_children() {
+        let expr = CompoundPredicate::And(vec![eq("a"), eq("a")]);
+        assert_eq!(expr.simplify(), eq("a"));
+    }
+
+    #[test]
+    fn simplify_collapses_double_negation() {
+        let expr = CompoundPredicate::Not(Box::new(CompoundPredicate::Not(Box::new(eq("a")))));
+        assert_eq!(expr.simplify(), eq("a"));
+    }
+
+    #[test]
+    fn simplify_orders_commutative_children_deterministically() {
+        let forward = CompoundPredicate::And(vec![eq("a"), eq("b")]);
+        let backward = CompoundPredicate::And(vec![eq("b"), eq("a")]);
+        assert_eq!(forward.simplify(), backward.simplify());
+    }
+
+    #[test]
+    fn simplify_leaves_atoms_untouched() {
+        assert_eq!(eq("a").simplify(), eq("a"));
+    }
+}
+
+/// A standalone, untyped AST and parser for a small textual predicate language, meant for
+/// filters coming from a CLI flag, config file, or REST query string instead of only the typed
+/// [`prototype`](super) builder API, e.g.:
+///
+/// ```text
+/// account.id.eq "alice@wonderland" and (domain.id.starts_with "wonder" or asset.value.gt 100)
+/// ```
+///
+/// [`parse`] turns the text into an [`Expr`] tree — dotted projection paths, the atom
+/// constructor name already generated for that path's `*Prototype` by
+/// [`impl_predicate_atom!`] (`eq`, `contains`, `starts_with`, `is_empty`, `is_ok`, …), and its
+/// literal arguments — with no knowledge of which root type the expression will run against.
+/// [`Expr`]'s [`Display`](core::fmt::Display) impl emits the same syntax back out, so a parsed
+/// filter can be logged and re-parsed unchanged, and [`Expr::simplify`] gives it a canonical
+/// form so two filters that only differ in how they were assembled compare and serialize equal.
+///
+/// [`lower`] turns an [`Expr`] into a `CompoundPredicate<T>` by walking its `And`/`Or`/`Not`
+/// structure and delegating each atom to a caller-supplied resolver. The structural walk is
+/// real; what's missing is a schema-driven registry mapping a root type's dotted field paths to
+/// its `*Prototype` fields and constructors, which would let [`lower`] resolve atoms itself
+/// instead of asking the caller to. That registry doesn't exist yet in this crate, so callers
+/// wire up their own `resolve_atom` for their root type in the meantime — landing the registry
+/// so [`lower`] needs no caller-supplied resolver is tracked as follow-up work.
+#[cfg(feature = "query-dsl-text")]
+pub mod text {
+    use core::{fmt, iter::Peekable, str::CharIndices};
+
+    use super::*;
+
+    /// A literal argument to an atom constructor.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Literal {
+        /// A quoted string literal, e.g. `"alice@wonderland"`.
+        Str(String),
+        /// A bare numeric literal, e.g. `100` or `1.5`.
+        Numeric(Numeric),
+        /// Any other bare (unquoted) token, e.g. a public key's multibase form or a bare
+        /// identifier. Which concrete type it means — `Name`, `PublicKey`, `HashOf<_>`, ... —
+        /// depends on the target field and is resolved when lowering against a root type.
+        Raw(String),
+    }
+
+    impl fmt::Display for Literal {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Literal::Str(s) => write!(f, "{s:?}"),
+                Literal::Numeric(n) => write!(f, "{n}"),
+                Literal::Raw(s) => write!(f, "{s}"),
+            }
+        }
+    }
+
+    /// An untyped predicate expression, parsed from the textual query language by [`parse`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Expr {
+        /// `lhs and rhs`
+        And(Box<Expr>, Box<Expr>),
+        /// `lhs or rhs`
+        Or(Box<Expr>, Box<Expr>),
+        /// `not inner`
+        Not(Box<Expr>),
+        /// `path.to.field.method arg1 arg2 ...`, e.g. `domain.id.starts_with "wonder"`.
+        Atom {
+            /// Dotted projection path into the root type, e.g. `["domain", "id"]`.
+            path: Vec<String>,
+            /// Name of the atom constructor, as generated by `impl_predicate_atom!`.
+            method: String,
+            /// Arguments passed to the constructor.
+            args: Vec<Literal>,
+        },
+    }
+
+    impl fmt::Display for Expr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Expr::And(lhs, rhs) => write!(f, "({lhs} and {rhs})"),
+                Expr::Or(lhs, rhs) => write!(f, "({lhs} or {rhs})"),
+                Expr::Not(inner) => write!(f, "not {inner}"),
+                Expr::Atom { path, method, args } => {
+                    write!(f, "{}.{method}", path.join("."))?;
+                    for arg in args {
+                        write!(f, " {arg}")?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    impl Expr {
+        /// Produce a canonical, flattened form of this expression: nested `And`/`Or` nodes of
+        /// the same kind collapse into a single flat chain, structurally-equal children of a
+        /// commutative node are deduplicated, double negation collapses to its inner
+        /// expression, and the children of commutative nodes are sorted into a deterministic
+        /// order. Two structurally equivalent filters therefore simplify, and so serialize, to
+        /// the same text — letting a caller key an evaluation cache on that canonical form
+        /// instead of re-deriving the same result for filters that only differ in how they
+        /// were assembled.
+        ///
+        /// This canonicalizes the parsed *text* form only, for callers working with filters as
+        /// text (logging, caching by string key, deduplicating submitted filters). It does not
+        /// touch any [`CompoundPredicate`] produced from the expression by [`lower`]; that typed
+        /// form has its own [`CompoundPredicate::simplify`].
+        #[must_use]
+        pub fn simplify(self) -> Self {
+            match self {
+                Expr::Not(inner) => match inner.simplify() {
+                    Expr::Not(doubly_inner) => *doubly_inner,
+                    simplified => Expr::Not(Box::new(simplified)),
+                },
+                Expr::And(lhs, rhs) => {
+                    let mut children = Vec::new();
+                    flatten_and(Expr::And(lhs, rhs), &mut children);
+                    fold_commutative(children, Expr::And)
+                }
+                Expr::Or(lhs, rhs) => {
+                    let mut children = Vec::new();
+                    flatten_or(Expr::Or(lhs, rhs), &mut children);
+                    fold_commutative(children, Expr::Or)
+                }
+                atom @ Expr::Atom { .. } => atom,
+            }
+        }
+    }
+
+    /// Recursively gather the operands of a (possibly nested) `And` chain, simplifying each
+    /// non-`And` operand along the way.
+    fn flatten_and(expr: Expr, out: &mut Vec<Expr>) {
+        match expr {
+            Expr::And(lhs, rhs) => {
+                flatten_and(*lhs, out);
+                flatten_and(*rhs, out);
+            }
+            other => out.push(other.simplify()),
+        }
+    }
+
+    /// Recursively gather the operands of a (possibly nested) `Or` chain, simplifying each
+    /// non-`Or` operand along the way.
+    fn flatten_or(expr: Expr, out: &mut Vec<Expr>) {
+        match expr {
+            Expr::Or(lhs, rhs) => {
+                flatten_or(*lhs, out);
+                flatten_or(*rhs, out);
+            }
+            other => out.push(other.simplify()),
+        }
+    }
+
+    /// Sort and deduplicate flattened operands, then fold them back up with `combine`, giving a
+    /// deterministic tree shape regardless of how the operands were originally nested.
+    fn fold_commutative(mut children: Vec<Expr>, combine: fn(Box<Expr>, Box<Expr>) -> Expr) -> Expr {
+        children.sort_by_key(ToString::to_string);
+        children.dedup();
+        let mut operands = children.into_iter();
+        let mut result = operands
+            .next()
+            .expect("flattening an `And`/`Or` always yields at least one operand");
+        for child in operands {
+            result = combine(Box::new(result), Box::new(child));
+        }
+        result
+    }
+
+    /// An error produced by [`lower`] when an atom's dotted path and method name don't resolve
+    /// against the root type being lowered against.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UnknownFieldError {
+        /// The dotted path that couldn't be resolved, e.g. `["domain", "id"]`.
+        pub path: Vec<String>,
+        /// The atom constructor method that couldn't be resolved, e.g. `"starts_with"`.
+        pub method: String,
+    }
+
+    impl fmt::Display for UnknownFieldError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "unknown field or method `{}.{}`", self.path.join("."), self.method)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for UnknownFieldError {}
+
+    /// Lower a parsed [`Expr`] into a `CompoundPredicate<T>` against some root type.
+    ///
+    /// This walks `expr`'s `And`/`Or`/`Not` structure directly, and resolves each `Atom` by
+    /// calling `resolve_atom` with its dotted path, constructor method name, and arguments.
+    /// `resolve_atom` should return the atom value for the root type's matching `*Prototype`
+    /// field and constructor, or [`None`] if `path`/`method` don't resolve against it — see
+    /// this module's docs for why that resolution isn't done here.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownFieldError`] for the first atom `resolve_atom` can't resolve.
+    pub fn lower<T>(
+        expr: Expr,
+        resolve_atom: &impl Fn(&[String], &str, &[Literal]) -> Option<T>,
+    ) -> Result<CompoundPredicate<T>, UnknownFieldError>
+    where
+        T: Clone + PartialEq + fmt::Debug,
+    {
+        match expr {
+            Expr::And(lhs, rhs) => Ok(CompoundPredicate::And(vec![
+                lower(*lhs, resolve_atom)?,
+                lower(*rhs, resolve_atom)?,
+            ])),
+            Expr::Or(lhs, rhs) => Ok(CompoundPredicate::Or(vec![
+                lower(*lhs, resolve_atom)?,
+                lower(*rhs, resolve_atom)?,
+            ])),
+            Expr::Not(inner) => Ok(CompoundPredicate::Not(Box::new(lower(*inner, resolve_atom)?))),
+            Expr::Atom { path, method, args } => {
+                resolve_atom(&path, &method, &args)
+                    .map(CompoundPredicate::Atom)
+                    .ok_or(UnknownFieldError { path, method })
+            }
+        }
+    }
+
+    /// An error produced by [`parse`], with the byte offset into the input it was found at.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ParseError {
+        /// Human-readable description of the problem.
+        pub message: String,
+        /// Byte offset into the input the error was found at.
+        pub position: usize,
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} (at byte {})", self.message, self.position)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParseError {}
+
+    /// Parse `input` as a predicate expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if `input` is not well-formed, e.g. unbalanced parentheses, a
+    /// dangling operator, or an unterminated string literal.
+    pub fn parse(input: &str) -> Result<Expr, ParseError> {
+        let mut parser = Parser {
+            input,
+            chars: input.char_indices().peekable(),
+        };
+        let expr = parser.parse_or()?;
+        parser.skip_whitespace();
+        match parser.chars.peek() {
+            None => Ok(expr),
+            Some(&(position, _)) => Err(ParseError {
+                message: "unexpected trailing input".into(),
+                position,
+            }),
+        }
+    }
+
+    struct Parser<'a> {
+        input: &'a str,
+        chars: Peekable<CharIndices<'a>>,
+    }
+
+    impl<'a> Parser<'a> {
+        fn position(&mut self) -> usize {
+            self.chars.peek().map_or(self.input.len(), |&(pos, _)| pos)
+        }
+
+        fn skip_whitespace(&mut self) {
+            while matches!(self.chars.peek(), Some((_, c)) if c.is_whitespace()) {
+                self.chars.next();
+            }
+        }
+
+        fn error(&mut self, message: impl Into<String>) -> ParseError {
+            ParseError {
+                message: message.into(),
+                position: self.position(),
+            }
+        }
+
+        /// Consume `keyword` as a whole word (not a prefix of a longer identifier) if it's next.
+        fn eat_keyword(&mut self, keyword: &str) -> bool {
+            self.skip_whitespace();
+            let start = self.position();
+            let rest = &self.input[start..];
+            if !rest.starts_with(keyword) {
+                return false;
+            }
+            let after = rest[keyword.len()..].chars().next();
+            if after.is_some_and(is_ident_char) {
+                return false;
+            }
+            for _ in 0..keyword.chars().count() {
+                self.chars.next();
+            }
+            true
+        }
+
+        fn eat_char(&mut self, expected: char) -> bool {
+            self.skip_whitespace();
+            if self.chars.peek().map(|&(_, c)| c) == Some(expected) {
+                self.chars.next();
+                true
+            } else {
+                false
+            }
+        }
+
+        fn parse_or(&mut self) -> Result<Expr, ParseError> {
+            let mut lhs = self.parse_and()?;
+            while self.eat_keyword("or") {
+                let rhs = self.parse_and()?;
+                lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+            }
+            Ok(lhs)
+        }
+
+        fn parse_and(&mut self) -> Result<Expr, ParseError> {
+            let mut lhs = self.parse_unary()?;
+            while self.eat_keyword("and") {
+                let rhs = self.parse_unary()?;
+                lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+            }
+            Ok(lhs)
+        }
+
+        fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+            if self.eat_keyword("not") {
+                return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+            }
+            self.parse_primary()
+        }
+
+        fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+            if self.eat_char('(') {
+                let inner = self.parse_or()?;
+                if !self.eat_char(')') {
+                    return Err(self.error("expected closing ')'"));
+                }
+                return Ok(inner);
+            }
+            self.parse_atom()
+        }
+
+        fn parse_atom(&mut self) -> Result<Expr, ParseError> {
+            let mut segments = vec![self.parse_ident()?];
+            while self.eat_char('.') {
+                segments.push(self.parse_ident()?);
+            }
+            if segments.len() < 2 {
+                return Err(self.error("expected a dotted path ending in a method name"));
+            }
+            let method = segments.pop().expect("just checked len >= 2");
+
+            let mut args = Vec::new();
+            loop {
+                self.skip_whitespace();
+                match self.chars.peek() {
+                    Some((_, '"')) => args.push(Literal::Str(self.parse_string()?)),
+                    Some((_, c)) if c.is_ascii_digit() || *c == '-' => {
+                        args.push(self.parse_numeric()?);
+                    }
+                    Some((_, c)) if is_ident_start(*c) => {
+                        if self.peek_ident().as_deref() == Some("and")
+                            || self.peek_ident().as_deref() == Some("or")
+                        {
+                            // Not an argument — leave it for the caller's `eat_keyword`.
+                            break;
+                        }
+                        args.push(Literal::Raw(self.parse_ident()?));
+                    }
+                    _ => break,
+                }
+            }
+
+            Ok(Expr::Atom {
+                path: segments,
+                method,
+                args,
+            })
+        }
+
+        /// Look at the identifier starting at the current position, if any, without consuming
+        /// it — used to decide whether an upcoming word is an argument or the `and`/`or`
+        /// keyword ending the current atom's argument list.
+        fn peek_ident(&mut self) -> Option<String> {
+            let mut lookahead = self.chars.clone();
+            let &(start, first) = lookahead.peek()?;
+            if !is_ident_start(first) {
+                return None;
+            }
+            let mut end = start + first.len_utf8();
+            lookahead.next();
+            while let Some(&(pos, c)) = lookahead.peek() {
+                if !is_ident_char(c) {
+                    break;
+                }
+                end = pos + c.len_utf8();
+                lookahead.next();
+            }
+            Some(self.input[start..end].to_owned())
+        }
+
+        fn parse_ident(&mut self) -> Result<String, ParseError> {
+            self.skip_whitespace();
+            let start = self.position();
+            match self.chars.peek() {
+                Some((_, c)) if is_ident_start(*c) => {}
+                _ => return Err(self.error("expected an identifier")),
+            }
+            while matches!(self.chars.peek(), Some((_, c)) if is_ident_char(*c)) {
+                self.chars.next();
+            }
+            let end = self.position();
+            Ok(self.input[start..end].to_owned())
+        }
+
+        fn parse_string(&mut self) -> Result<String, ParseError> {
+            self.chars.next(); // opening quote
+            let mut value = String::new();
+            loop {
+                match self.chars.next() {
+                    Some((_, '"')) => return Ok(value),
+                    Some((_, '\\')) => match self.chars.next() {
+                        Some((_, c)) => value.push(c),
+                        None => return Err(self.error("unterminated string literal")),
+                    },
+                    Some((_, c)) => value.push(c),
+                    None => return Err(self.error("unterminated string literal")),
+                }
+            }
+        }
+
+        fn parse_numeric(&mut self) -> Result<Literal, ParseError> {
+            let start = self.position();
+            if self.chars.peek().map(|&(_, c)| c) == Some('-') {
+                self.chars.next();
+            }
+            while matches!(self.chars.peek(), Some((_, c)) if c.is_ascii_digit() || *c == '.') {
+                self.chars.next();
+            }
+            let end = self.position();
+            let token = &self.input[start..end];
+            token
+                .parse::<Numeric>()
+                .map(Literal::Numeric)
+                .map_err(|_| ParseError {
+                    message: format!("invalid numeric literal {token:?}"),
+                    position: start,
+                })
+        }
+    }
+
+    fn is_ident_start(c: char) -> bool {
+        c.is_alphabetic() || c == '_'
+    }
+
+    fn is_ident_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parses_simple_atom() {
+            let expr = parse(r#"account.id.eq "alice@wonderland""#).unwrap();
+            assert_eq!(
+                expr,
+                Expr::Atom {
+                    path: vec!["account".to_owned(), "id".to_owned()],
+                    method: "eq".to_owned(),
+                    args: vec![Literal::Str("alice@wonderland".to_owned())],
+                }
+            );
+        }
+
+        #[test]
+        fn parses_boolean_combinators_with_precedence() {
+            let expr = parse(
+                r#"account.id.eq "alice@wonderland" and (domain.id.starts_with "wonder" or asset.value.gt 100)"#,
+            )
+            .unwrap();
+            assert!(matches!(expr, Expr::And(..)));
+        }
+
+        #[test]
+        fn parses_not_and_zero_arg_atom() {
+            let expr = parse("not signed_block.is_empty").unwrap();
+            assert_eq!(
+                expr,
+                Expr::Not(Box::new(Expr::Atom {
+                    path: vec!["signed_block".to_owned()],
+                    method: "is_empty".to_owned(),
+                    args: vec![],
+                }))
+            );
+        }
+
+        #[test]
+        fn display_round_trips_through_parse() {
+            let original =
+                r#"(account.id.eq "alice@wonderland" and domain.id.starts_with "wonder")"#;
+            let expr = parse(original).unwrap();
+            let reparsed = parse(&expr.to_string()).unwrap();
+            assert_eq!(expr, reparsed);
+        }
+
+        #[test]
+        fn rejects_unbalanced_parens() {
+            assert!(parse("(account.id.eq \"a\"").is_err());
+        }
+
+        #[test]
+        fn simplify_flattens_nested_same_kind_nodes() {
+            let nested = parse("a.eq 1 and (b.eq 2 and c.eq 3)").unwrap();
+            let flat = parse("a.eq 1 and b.eq 2 and c.eq 3").unwrap();
+            assert_eq!(nested.simplify(), flat.simplify());
+        }
+
+        #[test]
+        fn simplify_deduplicates_structurally_equal_children() {
+            let expr = parse("a.eq 1 and a.eq 1").unwrap();
+            assert_eq!(expr.simplify(), parse("a.eq 1").unwrap());
+        }
+
+        #[test]
+        fn simplify_collapses_double_negation() {
+            let expr = parse("not not a.is_ok").unwrap();
+            assert_eq!(expr.simplify(), parse("a.is_ok").unwrap());
+        }
+
+        #[test]
+        fn simplify_orders_commutative_children_deterministically() {
+            let forward = parse("a.eq 1 and b.eq 2").unwrap();
+            let backward = parse("b.eq 2 and a.eq 1").unwrap();
+            assert_eq!(forward.simplify(), backward.simplify());
+        }
+
+        /// A minimal stand-in for a schema-driven field registry, resolving `name.eq "..."` /
+        /// `name.contains "..."` against [`StringPredicateAtom`] and nothing else.
+        fn resolve_name_atom(
+            path: &[String],
+            method: &str,
+            args: &[Literal],
+        ) -> Option<StringPredicateAtom> {
+            if path != ["name".to_owned()] {
+                return None;
+            }
+            let Some(Literal::Str(arg)) = args.first() else {
+                return None;
+            };
+            match method {
+                "eq" => Some(StringPredicateAtom::Equals(arg.clone())),
+                "contains" => Some(StringPredicateAtom::Contains(arg.clone())),
+                _ => None,
+            }
+        }
+
+        #[test]
+        fn lower_translates_boolean_structure_and_resolved_atoms() {
+            let expr = parse(r#"name.eq "alice" and not name.contains "bob""#).unwrap();
+            let predicate = lower(expr, &resolve_name_atom).expect("all atoms resolve");
+
+            assert_eq!(
+                predicate,
+                CompoundPredicate::And(vec![
+                    CompoundPredicate::Atom(StringPredicateAtom::Equals("alice".to_owned())),
+                    CompoundPredicate::Not(Box::new(CompoundPredicate::Atom(
+                        StringPredicateAtom::Contains("bob".to_owned())
+                    ))),
+                ])
+            );
+        }
+
+        #[test]
+        fn lower_reports_the_unresolved_field() {
+            let expr = parse(r#"unknown.field.eq "x""#).unwrap();
+            let error = lower(expr, &resolve_name_atom).unwrap_err();
+
+            assert_eq!(
+                error,
+                UnknownFieldError {
+                    path: vec!["unknown".to_owned(), "field".to_owned()],
+                    method: "eq".to_owned(),
+                }
+            );
+        }
+    }
+}
+
 pub mod prelude {
     //! Re-export all predicate boxes for a glob import `(::*)`
     pub use super::{
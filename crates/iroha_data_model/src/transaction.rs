@@ -4,12 +4,12 @@ use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     iter::IntoIterator,
-    num::{NonZeroU32, NonZeroU64},
+    num::{NonZeroU32, NonZeroU64, NonZeroU8},
     time::Duration,
 };
 
 use derive_more::{DebugCustom, Deref, Display, From, TryInto};
-use iroha_crypto::{HashOf, Signature, SignatureOf};
+use iroha_crypto::{Hash, HashOf, PublicKey, Signature, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 #[cfg(feature = "std")]
@@ -57,6 +57,45 @@ mod model {
         Instructions(ConstVec<InstructionBox>),
         /// WebAssembly smartcontract
         Wasm(WasmSmartContract),
+        /// Reference to a previously-uploaded [`WasmSmartContract`] by its hash, rather than
+        /// the inline blob. Lets clients resubmitting the same contract avoid re-shipping it;
+        /// validation resolves the hash against the stored blob and rejects the transaction if
+        /// it is unknown.
+        WasmById(HashOf<WasmSmartContract>),
+        /// An atomically-committed batch of independently-authored and independently-signed
+        /// inner transactions: either every item executes, or the whole transaction is
+        /// rejected and none of them take effect.
+        #[debug(fmt = "{_0:?}")]
+        Batch(Vec<BatchTransaction>),
+    }
+
+    /// Classification of a transaction's executable content.
+    ///
+    /// The mempool applies separate size/instruction caps and scheduling per lane, so large
+    /// install-WASM transactions can't crowd out cheap instruction transactions.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum TransactionLane {
+        /// Ordered set of instructions that does not execute a trigger.
+        Instructions,
+        /// References a previously-uploaded WASM smart contract by hash.
+        Wasm,
+        /// Inlines a full WASM smart contract blob, e.g. uploading it for the first time.
+        InstallWasm,
+        /// Ordered set of instructions that includes at least one `ExecuteTrigger`.
+        Trigger,
     }
 
     /// Wrapper for byte representation of [`Executable::Wasm`].
@@ -86,6 +125,203 @@ mod model {
         pub(super) Vec<u8>,
     );
 
+    /// A named, independently hashable part of a transaction's content.
+    ///
+    /// Sections let a peer that has validated a transaction drop the parts it no longer needs
+    /// for execution (e.g. a large memo) while keeping the remaining content verifiable against
+    /// [`TxCommitments`].
+    #[derive(
+        DebugCustom,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum Section {
+        /// ISI or a `WebAssembly` smart contract.
+        #[debug(fmt = "{_0:?}")]
+        Executable(Executable),
+        /// Store for additional information.
+        #[debug(fmt = "{_0:?}")]
+        Metadata(Metadata),
+        /// Opaque memo bytes, not interpreted by the protocol.
+        #[debug(fmt = "Memo(len = {})", "_0.len()")]
+        Memo(Vec<u8>),
+        /// Header fields of a top-level [`TransactionPayload`]. Unlike the other sections,
+        /// this one is never dropped after validation: every field it covers is required for
+        /// replay protection, scheduling, or expiry, so it's always checked.
+        #[debug(fmt = "{_0:?}")]
+        Header(TransactionHeader),
+        /// Header fields of a [`BatchTransaction`]. Covers the data [`TxCommitments::header`]
+        /// needs to bind a batch item's signature to, beyond its own [`Section::Executable`].
+        #[debug(fmt = "{_0:?}")]
+        BatchHeader(BatchTransactionHeader),
+    }
+
+    /// Header fields of a [`TransactionPayload`] committed to by [`TxCommitments::header`].
+    ///
+    /// None of these are ever dropped the way [`Section::Metadata`] or [`Section::Memo`] can
+    /// be, so they're hashed together as a single unit rather than individually.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct TransactionHeader {
+        /// See [`TransactionPayload::chain`].
+        pub chain: ChainId,
+        /// See [`TransactionPayload::authority`].
+        pub authority: AccountId,
+        /// See [`TransactionPayload::creation_time_ms`].
+        pub creation_time_ms: u64,
+        /// See [`TransactionPayload::lane`].
+        pub lane: TransactionLane,
+        /// See [`TransactionPayload::time_to_live_ms`].
+        pub time_to_live_ms: Option<NonZeroU64>,
+        /// See [`TransactionPayload::nonce`].
+        pub nonce: Option<NonZeroU32>,
+        /// See [`TransactionPayload::gas_limit`].
+        pub gas_limit: Option<NonZeroU64>,
+        /// See [`TransactionPayload::gas_price_tolerance`].
+        pub gas_price_tolerance: Option<NonZeroU64>,
+    }
+
+    /// Header fields of a [`BatchTransaction`] committed to by [`TxCommitments::header`].
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct BatchTransactionHeader {
+        /// See [`BatchTransaction::authority`].
+        pub authority: AccountId,
+    }
+
+    /// Per-section commitments of a [`TransactionPayload`] or [`BatchTransaction`].
+    ///
+    /// These are what the transaction authenticator actually signs over. Verifying a
+    /// transaction recomputes the hash of each section still present in the payload and checks
+    /// it against the corresponding commitment here; a dropped section simply isn't checked,
+    /// so the signature keeps verifying against whatever sections remain. [`Self::header`]
+    /// covers the fields that are never droppable and is always checked.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct TxCommitments {
+        /// Commitment to the [`Section::Header`] or [`Section::BatchHeader`] section.
+        pub header: HashOf<Section>,
+        /// Commitment to the [`Section::Executable`] section.
+        pub executable: HashOf<Section>,
+        /// Commitment to the [`Section::Metadata`] section.
+        pub metadata: HashOf<Section>,
+        /// Commitment to the [`Section::Memo`] section.
+        pub memo: HashOf<Section>,
+    }
+
+    /// One inner transaction of an [`Executable::Batch`]: an executable authored and signed by
+    /// its own authority, committed and verified all-or-nothing alongside its batch siblings.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct BatchTransaction {
+        /// Account authorized to run [`Self::executable`].
+        pub authority: AccountId,
+        /// ISI or a `WebAssembly` smart contract run by [`Self::authority`].
+        pub executable: Executable,
+        /// Commitment to [`Self::executable`] (with empty metadata and memo sections), signed
+        /// over by [`Self::signature`].
+        pub commitments: TxCommitments,
+        /// Proof that [`Self::authority`] authorized [`Self::executable`].
+        pub signature: TransactionAuthenticator,
+    }
+
+    /// Ordered sibling hashes proving a leaf's inclusion in a Merkle accumulator, from the
+    /// leaf's own level up to the root.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[serde(transparent)]
+    pub struct AccumulatorProof(pub Vec<Hash>);
+
+    /// A verifiable receipt that a transaction was committed: its entrypoint hash and result
+    /// hash, together with an [`AccumulatorProof`] of inclusion against a published block root.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct TransactionInfoWithProof {
+        /// Hash of the committed transaction's entrypoint.
+        pub transaction_hash: HashOf<TransactionEntrypoint>,
+        /// Hash of the transaction's execution result.
+        pub result_hash: HashOf<TransactionResult>,
+        /// Proof that [`Self::transaction_hash`] is included in the accumulator at
+        /// [`Self::leaf_index`].
+        pub proof: AccumulatorProof,
+        /// Position of [`Self::transaction_hash`] among the accumulator's leaves.
+        pub leaf_index: u64,
+    }
+
     /// Iroha transaction payload.
     #[derive(
         Debug,
@@ -109,14 +345,32 @@ mod model {
         pub authority: AccountId,
         /// Creation timestamp (unix time in milliseconds).
         pub creation_time_ms: u64,
-        /// ISI or a `WebAssembly` smart contract.
+        /// Commitments to every section, signed over instead of the sections themselves.
+        pub commitments: TxCommitments,
+        /// ISI or a `WebAssembly` smart contract. Always present: a peer needs this to execute
+        /// the transaction at all.
         pub instructions: Executable,
+        /// Lane this transaction is classified under, for mempool scheduling and per-category
+        /// size/instruction limits. Must match [`TransactionLane::for_executable`] of
+        /// [`Self::instructions`]; a mismatch is rejected with
+        /// [`error::TransactionRejectionReason::InvalidTransactionLane`].
+        pub lane: TransactionLane,
+        /// Store for additional information. May be [`None`] if this section was dropped after
+        /// validation; its commitment in [`Self::commitments`] is still meaningful.
+        pub metadata: Option<Metadata>,
+        /// Opaque memo bytes. May be [`None`] if this section was dropped after validation; its
+        /// commitment in [`Self::commitments`] is still meaningful.
+        pub memo: Option<Vec<u8>>,
         /// If transaction is not committed by this time it will be dropped.
         pub time_to_live_ms: Option<NonZeroU64>,
         /// Random value to make different hashes for transactions which occur repeatedly and simultaneously.
         pub nonce: Option<NonZeroU32>,
-        /// Store for additional information.
-        pub metadata: Metadata,
+        /// Upper bound on the gas this transaction's execution may consume. Execution aborts
+        /// with [`error::TransactionRejectionReason::LimitCheck`] once exhausted.
+        pub gas_limit: Option<NonZeroU64>,
+        /// Highest gas price the signer is willing to pay. The transaction is rejected before
+        /// execution if the current network gas price exceeds this.
+        pub gas_price_tolerance: Option<NonZeroU64>,
     }
 
     /// Signature of transaction
@@ -133,7 +387,42 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    pub struct TransactionSignature(pub SignatureOf<TransactionPayload>);
+    pub struct TransactionSignature(pub SignatureOf<TxCommitments>);
+
+    /// Proof that a transaction was authorized by its [`TransactionPayload::authority`].
+    ///
+    /// Either a single signature, matching today's single-key accounts, or a threshold
+    /// multi-signature for accounts controlled by several keys (e.g. multisig accounts,
+    /// hardware + hot-key co-signing).
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum TransactionAuthenticator {
+        /// A single signature over the transaction's [`TxCommitments`].
+        Single(TransactionSignature),
+        /// A threshold signature over the transaction's [`TxCommitments`].
+        ///
+        /// Each entry pairs a signer index, into the authority account's ordered set of
+        /// public keys, with the signature produced by the corresponding key.
+        /// Entries are always kept sorted by index, so the encoding (and thus the
+        /// transaction hash) is stable regardless of the order signatures were collected in.
+        MultiSignature {
+            /// Signatures, sorted by signer index.
+            signatures: Vec<(u8, SignatureOf<TxCommitments>)>,
+            /// Minimum number of valid, distinct signatures required for authorization.
+            threshold: NonZeroU8,
+        },
+    }
 
     /// Transaction that contains a signature
     ///
@@ -159,19 +448,23 @@ mod model {
     #[display(fmt = "{}", "self.hash()")]
     #[ffi_type]
     pub struct SignedTransactionV1 {
-        /// Signature of [`Self::payload`].
-        pub(super) signature: TransactionSignature,
+        /// Authenticator proving [`Self::payload`] was authorized by its authority.
+        pub(super) signature: TransactionAuthenticator,
         /// Payload of the transaction.
         pub(super) payload: TransactionPayload,
     }
 
     /// Structure that represents the initial state of a transaction before the transaction receives any signatures.
     #[derive(Debug, Clone)]
-    #[repr(transparent)]
     #[must_use]
     pub struct TransactionBuilder {
         /// [`Transaction`] payload.
         pub(super) payload: TransactionPayload,
+        /// Signatures collected so far for a multisig authenticator in progress.
+        ///
+        /// Populated by [`TransactionBuilder::sign_with`] and consumed by
+        /// [`TransactionBuilder::finish_multisig`].
+        pub(super) multisig_signatures: Vec<(u8, SignatureOf<TxCommitments>)>,
     }
 
     /// Initial execution step of a transaction, which may invoke data triggers.
@@ -252,8 +545,37 @@ mod model {
     pub type TransactionResultInner =
         Result<DataTriggerSequence, error::TransactionRejectionReason>;
 
-    /// Sequence of data trigger execution steps.
-    pub type DataTriggerSequence = Vec<DataTriggerStep>;
+    /// Sequence of data trigger execution steps, grouped by the inner transaction that caused
+    /// them.
+    ///
+    /// A non-[`Executable::Batch`] transaction produces exactly one [`DataTriggerGroup`],
+    /// covering its whole executable; an [`Executable::Batch`] transaction produces one group
+    /// per inner [`BatchTransaction`], in the same order, so the steps a given item triggered
+    /// can be told apart from its siblings' the same way [`BatchItemFailure::index`] attributes
+    /// a rejection to a specific item.
+    pub type DataTriggerSequence = Vec<DataTriggerGroup>;
+
+    /// Data trigger steps caused by a single inner transaction of a [`DataTriggerSequence`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        From,
+        Deref,
+        IntoSchema,
+    )]
+    #[display(fmt = "DataTriggerGroup")]
+    #[serde(transparent)]
+    #[ffi_type]
+    pub struct DataTriggerGroup(pub Vec<DataTriggerStep>);
 
     /// Single execution step of the data trigger.
     #[derive(
@@ -319,6 +641,98 @@ impl From<WasmSmartContract> for Executable {
     }
 }
 
+impl Executable {
+    /// Size of this executable as it appears in a transaction, in bytes.
+    ///
+    /// A [`Self::WasmById`] reference accounts only for the hash itself, not the size of the
+    /// blob it points to, since that blob is stored on-chain and not shipped with the
+    /// transaction.
+    pub fn size_bytes(&self) -> usize {
+        match self {
+            Self::Instructions(instructions) => instructions
+                .iter()
+                .map(|instruction| core::mem::size_of_val(instruction))
+                .sum(),
+            Self::Wasm(wasm) => wasm.size_bytes(),
+            Self::WasmById(_hash) => core::mem::size_of::<HashOf<WasmSmartContract>>(),
+            Self::Batch(items) => items
+                .iter()
+                .map(|item| item.executable.size_bytes())
+                .sum(),
+        }
+    }
+}
+
+impl Display for TransactionLane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Instructions => "instructions",
+            Self::Wasm => "wasm",
+            Self::InstallWasm => "install-wasm",
+            Self::Trigger => "trigger",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl TransactionLane {
+    /// How restrictive this lane's size/instruction cap is, for picking the tightest lane
+    /// among several candidates. Higher means more restrictive.
+    fn restrictiveness(self) -> u8 {
+        match self {
+            Self::Instructions => 0,
+            Self::Trigger => 1,
+            Self::Wasm => 2,
+            Self::InstallWasm => 3,
+        }
+    }
+
+    /// The lane [`Executable`] content is classified under.
+    ///
+    /// For [`Executable::Batch`], this is the most restrictive lane among the batch's items'
+    /// own executables (recursing into any nested batch), not a blanket
+    /// [`Self::Instructions`]: a [`BatchTransaction`] item has no `lane` field of its own to
+    /// validate yet, so if the batch as a whole were classified independently of what its
+    /// items actually contain, an item could inline e.g. a large `Executable::Wasm` blob and
+    /// dodge [`Self::InstallWasm`]'s size cap by hiding behind the outer batch's lane.
+    pub fn for_executable(executable: &Executable) -> Self {
+        Self::for_executable_at_depth(executable, 0)
+    }
+
+    /// Recursion limit for [`Self::for_executable`]'s descent into nested
+    /// [`Executable::Batch`]es. A batch nested deeper than this is already pathological input;
+    /// rather than risk overflowing the stack computing its lane, treat it as maximally
+    /// restrictive.
+    const MAX_BATCH_NESTING_DEPTH: usize = 16;
+
+    fn for_executable_at_depth(executable: &Executable, depth: usize) -> Self {
+        match executable {
+            Executable::Instructions(instructions) => {
+                if instructions
+                    .iter()
+                    .any(|instruction| matches!(instruction, InstructionBox::ExecuteTrigger(_)))
+                {
+                    Self::Trigger
+                } else {
+                    Self::Instructions
+                }
+            }
+            Executable::Wasm(_) => Self::InstallWasm,
+            Executable::WasmById(_) => Self::Wasm,
+            Executable::Batch(items) => {
+                if depth >= Self::MAX_BATCH_NESTING_DEPTH {
+                    return Self::InstallWasm;
+                }
+                items
+                    .iter()
+                    .map(|item| Self::for_executable_at_depth(&item.executable, depth + 1))
+                    .max_by_key(|lane| lane.restrictiveness())
+                    .unwrap_or(Self::Instructions)
+            }
+        }
+    }
+}
+
 impl AsRef<[u8]> for WasmSmartContract {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -338,6 +752,157 @@ impl WasmSmartContract {
     }
 }
 
+impl error::WasmExecutionFail {
+    /// Create [`Self`] from a free-form reason and, if the host engine could classify it, a
+    /// structured trap code.
+    pub fn new(reason: impl Into<String>, trap: Option<error::TrapCode>) -> Self {
+        Self {
+            reason: reason.into(),
+            trap,
+            backtrace: None,
+            panic_message: None,
+        }
+    }
+
+    /// Attach a captured call-frame backtrace. Only ever called by a `std` host, since walking
+    /// the engine's call stack and demangling symbols needs `feature = "std"` support.
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn with_backtrace(mut self, backtrace: Vec<error::FrameInfo>) -> Self {
+        self.backtrace = Some(backtrace);
+        self
+    }
+
+    /// Attach a Rust panic message recovered from the guest's linear memory after it trapped,
+    /// as read back by [`wasm_panic::panic_message_bytes`] from a contract built with the
+    /// `wasm-panic-handler` feature.
+    #[must_use]
+    pub fn with_panic_message(mut self, panic_message: impl Into<String>) -> Self {
+        self.panic_message = Some(panic_message.into());
+        self
+    }
+}
+
+impl TxCommitments {
+    /// Compute the commitments of a transaction's sections as they stand right now.
+    ///
+    /// `header` is the never-droppable [`Section::Header`] or [`Section::BatchHeader`] of the
+    /// transaction it's being computed for.
+    pub(crate) fn compute(
+        header: Section,
+        instructions: &Executable,
+        metadata: &Metadata,
+        memo: &[u8],
+    ) -> Self {
+        Self {
+            header: HashOf::new(&header),
+            executable: HashOf::new(&Section::Executable(instructions.clone())),
+            metadata: HashOf::new(&Section::Metadata(metadata.clone())),
+            memo: HashOf::new(&Section::Memo(memo.to_vec())),
+        }
+    }
+}
+
+/// Combine a left and right accumulator node into their parent, the same way on both build and
+/// verify so a proof folds back to the root that built it.
+fn combine_accumulator_nodes(left: &Hash, right: &Hash) -> Hash {
+    HashOf::<(Hash, Hash)>::new(&(left.clone(), right.clone())).into()
+}
+
+impl TransactionInfoWithProof {
+    /// Fold [`Self::transaction_hash`] up through [`Self::proof`]'s siblings, at each level
+    /// hashing `(current, sibling)` in the order dictated by the bit of [`Self::leaf_index`] at
+    /// that level, and check the recomputed root equals `expected_root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::AccumulatorProofError::RootMismatch`] if the recomputed root does not
+    /// match `expected_root`.
+    pub fn verify(
+        &self,
+        expected_root: HashOf<TransactionEntrypoint>,
+    ) -> Result<(), error::AccumulatorProofError> {
+        let mut current: Hash = self.transaction_hash.into();
+        let mut index = self.leaf_index;
+
+        for sibling in &self.proof.0 {
+            current = if index & 1 == 0 {
+                combine_accumulator_nodes(&current, sibling)
+            } else {
+                combine_accumulator_nodes(sibling, &current)
+            };
+            index >>= 1;
+        }
+
+        if current == expected_root.into() {
+            Ok(())
+        } else {
+            Err(error::AccumulatorProofError::RootMismatch)
+        }
+    }
+}
+
+/// Builds an in-memory Merkle accumulator over an ordered set of transaction entrypoint hashes
+/// and emits [`AccumulatorProof`]s of inclusion for any leaf.
+///
+/// An odd node out at a level (the tree isn't a complete binary tree) is paired with itself,
+/// the same convention used on both build and verify, so every level always folds exactly one
+/// sibling per proof entry.
+#[derive(Debug, Clone)]
+pub struct AccumulatorBuilder {
+    /// `levels[0]` is the leaves; each following level is the parents of the last, down to
+    /// `levels.last()`, which holds the single root.
+    levels: Vec<Vec<Hash>>,
+}
+
+impl AccumulatorBuilder {
+    /// Build the accumulator from an ordered slice of transaction entrypoint hashes, or
+    /// [`None`] if `leaves` is empty: an empty block is a legitimate state (see
+    /// [`crate::query::dsl::predicates::SignedBlockPredicateAtom::IsEmpty`]), but it has
+    /// no root to build an accumulator over.
+    pub fn new(leaves: &[HashOf<TransactionEntrypoint>]) -> Option<Self> {
+        if leaves.is_empty() {
+            return None;
+        }
+        let leaves: Vec<Hash> = leaves.iter().map(|hash| (*hash).into()).collect();
+
+        let mut levels = vec![leaves];
+        while levels.last().expect("just pushed").len() > 1 {
+            let level = levels.last().expect("just pushed");
+            let parent = level
+                .chunks(2)
+                .map(|pair| combine_accumulator_nodes(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            levels.push(parent);
+        }
+
+        Some(Self { levels })
+    }
+
+    /// The root of the accumulator, against which proofs from [`Self::proof`] verify.
+    pub fn root(&self) -> HashOf<TransactionEntrypoint> {
+        let root = self.levels.last().expect("always has at least one level")[0].clone();
+        HashOf::from_untyped_unchecked(root)
+    }
+
+    /// Proof of inclusion for the leaf at `leaf_index`, or [`None`] if out of range.
+    pub fn proof(&self, leaf_index: usize) -> Option<AccumulatorProof> {
+        if leaf_index >= self.levels.first()?.len() {
+            return None;
+        }
+
+        let mut index = leaf_index;
+        let mut siblings = Vec::with_capacity(self.levels.len().saturating_sub(1));
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = if index ^ 1 < level.len() { index ^ 1 } else { index };
+            siblings.push(level[sibling_index].clone());
+            index >>= 1;
+        }
+
+        Some(AccumulatorProof(siblings))
+    }
+}
+
 #[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
 declare_versioned!(SignedTransaction 1..2, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
 #[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
@@ -365,25 +930,55 @@ impl SignedTransaction {
         &tx.payload.authority
     }
 
-    /// Return transaction metadata.
+    /// Return transaction metadata, if the metadata section hasn't been dropped.
     #[inline]
-    pub fn metadata(&self) -> &Metadata {
+    pub fn metadata(&self) -> Option<&Metadata> {
         let SignedTransaction::V1(tx) = self;
-        &tx.payload.metadata
+        tx.payload.metadata.as_ref()
     }
 
-    /// Creation timestamp as [`core::time::Duration`]
+    /// Return the transaction's memo bytes, if the memo section hasn't been dropped.
     #[inline]
-    pub fn creation_time(&self) -> Duration {
+    pub fn memo(&self) -> Option<&[u8]> {
         let SignedTransaction::V1(tx) = self;
-        Duration::from_millis(tx.payload.creation_time_ms)
+        tx.payload.memo.as_deref()
     }
 
-    /// If transaction is not committed by this time it will be dropped.
+    /// Per-section commitments that the transaction's authenticator signs over.
     #[inline]
-    pub fn time_to_live(&self) -> Option<Duration> {
+    pub fn commitments(&self) -> &TxCommitments {
         let SignedTransaction::V1(tx) = self;
-        tx.payload
+        &tx.payload.commitments
+    }
+
+    /// Drop the metadata section, keeping its commitment intact so the signature still
+    /// verifies against the remaining sections.
+    #[cfg(feature = "transparent_api")]
+    pub fn drop_metadata_section(&mut self) {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.metadata = None;
+    }
+
+    /// Drop the memo section, keeping its commitment intact so the signature still verifies
+    /// against the remaining sections.
+    #[cfg(feature = "transparent_api")]
+    pub fn drop_memo_section(&mut self) {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.memo = None;
+    }
+
+    /// Creation timestamp as [`core::time::Duration`]
+    #[inline]
+    pub fn creation_time(&self) -> Duration {
+        let SignedTransaction::V1(tx) = self;
+        Duration::from_millis(tx.payload.creation_time_ms)
+    }
+
+    /// If transaction is not committed by this time it will be dropped.
+    #[inline]
+    pub fn time_to_live(&self) -> Option<Duration> {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload
             .time_to_live_ms
             .map(|ttl| Duration::from_millis(ttl.into()))
     }
@@ -402,9 +997,31 @@ impl SignedTransaction {
         &tx.payload.chain
     }
 
-    /// Return the transaction signature
+    /// Upper bound on the gas this transaction's execution may consume
+    #[inline]
+    pub fn gas_limit(&self) -> Option<NonZeroU64> {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.gas_limit
+    }
+
+    /// Highest gas price the signer is willing to pay for this transaction
     #[inline]
-    pub fn signature(&self) -> &TransactionSignature {
+    pub fn gas_price_tolerance(&self) -> Option<NonZeroU64> {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.gas_price_tolerance
+    }
+
+    /// Lane this transaction is classified under, for mempool scheduling and per-category
+    /// size/instruction limits
+    #[inline]
+    pub fn lane(&self) -> TransactionLane {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.lane
+    }
+
+    /// Return the transaction authenticator.
+    #[inline]
+    pub fn signature(&self) -> &TransactionAuthenticator {
         let SignedTransaction::V1(tx) = self;
         &tx.signature
     }
@@ -438,18 +1055,203 @@ impl SignedTransaction {
         *instructions = modified.into();
     }
 
+    /// Recompute the hash of every section still present in the payload and check it against
+    /// the corresponding commitment. A dropped section is simply not checked.
+    fn check_commitments(tx: &SignedTransactionV1) -> Result<(), error::SignatureVerificationError> {
+        use error::SignatureVerificationError as Error;
+
+        let header = Section::Header(TransactionHeader {
+            chain: tx.payload.chain.clone(),
+            authority: tx.payload.authority.clone(),
+            creation_time_ms: tx.payload.creation_time_ms,
+            lane: tx.payload.lane,
+            time_to_live_ms: tx.payload.time_to_live_ms,
+            nonce: tx.payload.nonce,
+            gas_limit: tx.payload.gas_limit,
+            gas_price_tolerance: tx.payload.gas_price_tolerance,
+        });
+        if HashOf::new(&header) != tx.payload.commitments.header {
+            return Err(Error::CommitmentMismatch { section: "header".into() });
+        }
+
+        if HashOf::new(&Section::Executable(tx.payload.instructions.clone()))
+            != tx.payload.commitments.executable
+        {
+            return Err(Error::CommitmentMismatch { section: "executable".into() });
+        }
+        if let Some(metadata) = &tx.payload.metadata {
+            if HashOf::new(&Section::Metadata(metadata.clone())) != tx.payload.commitments.metadata
+            {
+                return Err(Error::CommitmentMismatch { section: "metadata".into() });
+            }
+        }
+        if let Some(memo) = &tx.payload.memo {
+            if HashOf::new(&Section::Memo(memo.clone())) != tx.payload.commitments.memo {
+                return Err(Error::CommitmentMismatch { section: "memo".into() });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Verify transaction signature.
     ///
+    /// Recomputes the hash of every section still present and checks it against
+    /// [`TxCommitments`] before verifying the signature over the commitments themselves, the
+    /// same as [`Self::verify_authenticator`], so tampering with a present section, or with a
+    /// header field covered by [`TxCommitments::header`], is caught even though the signature
+    /// itself is computed over `commitments` rather than the full payload.
+    ///
     /// # Errors
     ///
-    /// Returns an error if signature verification fails.
+    /// Returns an error if a present section's hash doesn't match its commitment, if signature
+    /// verification fails, or if the authenticator is a
+    /// [`TransactionAuthenticator::MultiSignature`]: verifying one needs the authority's
+    /// ordered set of public keys, which this method doesn't take, so use
+    /// [`Self::verify_authenticator`] for transactions that may carry one.
     #[inline]
-    pub fn verify_signature(&self) -> Result<(), iroha_crypto::Error> {
+    pub fn verify_signature(&self) -> Result<(), error::SignatureVerificationError> {
+        use error::SignatureVerificationError as Error;
+
         let SignedTransaction::V1(tx) = self;
 
-        let TransactionSignature(signature) = &tx.signature;
+        Self::check_commitments(tx)?;
+
+        let TransactionAuthenticator::Single(TransactionSignature(signature)) = &tx.signature
+        else {
+            return Err(Error::MultiSignatureUnsupported);
+        };
 
-        signature.verify(&tx.payload.authority.signatory, &tx.payload)
+        signature
+            .verify(&tx.payload.authority.signatory, &tx.payload.commitments)
+            .map_err(Error::InvalidSignature)
+    }
+
+    /// Verify the transaction's authenticator against the authority's ordered set of
+    /// public keys.
+    ///
+    /// Recomputes the hash of every section still present and checks it against
+    /// [`TxCommitments`] before verifying the signature(s) over the commitments themselves,
+    /// so a transaction that had e.g. its memo dropped after validation still verifies.
+    ///
+    /// For [`TransactionAuthenticator::Single`] only `authorized_keys[0]` is consulted,
+    /// matching today's single-key [`AccountId`]; a multi-key account model that actually
+    /// stores this ordered key set is tracked separately and must be supplied by the caller
+    /// until then.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::SignatureVerificationError`] if a present section's hash doesn't match
+    /// its commitment, if a signer index is duplicated or out of range, if fewer than
+    /// `threshold` signatures verify, or if the lone signature of a
+    /// [`TransactionAuthenticator::Single`] fails to verify.
+    pub fn verify_authenticator(
+        &self,
+        authorized_keys: &[PublicKey],
+    ) -> Result<(), error::SignatureVerificationError> {
+        let SignedTransaction::V1(tx) = self;
+
+        Self::check_commitments(tx)?;
+
+        verify_authenticator_against(&tx.signature, &tx.payload.commitments, authorized_keys)
+    }
+
+    /// The inner transactions of an [`Executable::Batch`], or [`None`] if this transaction
+    /// isn't a batch.
+    #[inline]
+    pub fn inner_transactions(&self) -> Option<&[BatchTransaction]> {
+        let SignedTransaction::V1(tx) = self;
+        match &tx.payload.instructions {
+            Executable::Batch(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Shared verification logic behind [`SignedTransaction::verify_authenticator`] and
+/// [`BatchTransaction::verify`]: check a [`TransactionAuthenticator`] against the commitments
+/// it was produced over and the authority's ordered set of public keys.
+fn verify_authenticator_against(
+    signature: &TransactionAuthenticator,
+    commitments: &TxCommitments,
+    authorized_keys: &[PublicKey],
+) -> Result<(), error::SignatureVerificationError> {
+    use error::SignatureVerificationError as Error;
+
+    match signature {
+        TransactionAuthenticator::Single(TransactionSignature(signature)) => {
+            let key = authorized_keys.first().ok_or(Error::SignerIndexOutOfRange {
+                index: 0,
+                key_count: 0,
+            })?;
+            signature.verify(key, commitments).map_err(Error::InvalidSignature)
+        }
+        TransactionAuthenticator::MultiSignature {
+            signatures,
+            threshold,
+        } => {
+            let mut seen_indices = Vec::with_capacity(signatures.len());
+            let mut valid = 0u8;
+
+            for (index, signature) in signatures {
+                if seen_indices.contains(index) {
+                    return Err(Error::DuplicateSignerIndex { index: *index });
+                }
+                seen_indices.push(*index);
+
+                let key = authorized_keys.get(*index as usize).ok_or(
+                    Error::SignerIndexOutOfRange {
+                        index: *index,
+                        key_count: authorized_keys.len(),
+                    },
+                )?;
+
+                if signature.verify(key, commitments).is_ok() {
+                    valid = valid.saturating_add(1);
+                }
+            }
+
+            if valid >= threshold.get() {
+                Ok(())
+            } else {
+                Err(Error::ThresholdNotMet {
+                    valid,
+                    threshold: threshold.get(),
+                })
+            }
+        }
+    }
+}
+
+impl BatchTransaction {
+    /// Recompute the hash of [`Self::executable`] and of the batch item's header (currently
+    /// just [`Self::authority`]) and check them against [`Self::commitments`].
+    fn check_commitments(&self) -> Result<(), error::SignatureVerificationError> {
+        use error::SignatureVerificationError as Error;
+
+        let header = Section::BatchHeader(BatchTransactionHeader {
+            authority: self.authority.clone(),
+        });
+        if HashOf::new(&header) != self.commitments.header {
+            return Err(Error::CommitmentMismatch { section: "header".into() });
+        }
+        if HashOf::new(&Section::Executable(self.executable.clone())) != self.commitments.executable
+        {
+            return Err(Error::CommitmentMismatch { section: "executable".into() });
+        }
+
+        Ok(())
+    }
+
+    /// Verify this inner transaction's authenticator against its own commitments and its
+    /// authority's ordered set of public keys.
+    ///
+    /// # Errors
+    ///
+    /// See [`SignedTransaction::verify_authenticator`].
+    pub fn verify(&self, authorized_keys: &[PublicKey]) -> Result<(), error::SignatureVerificationError> {
+        self.check_commitments()?;
+        verify_authenticator_against(&self.signature, &self.commitments, authorized_keys)
     }
 }
 
@@ -477,16 +1279,36 @@ impl TransactionSignature {
 impl TransactionBuilder {
     #[cfg(feature = "std")]
     fn new_with_time(chain: ChainId, authority: AccountId, creation_time_ms: u64) -> Self {
+        let instructions = Vec::<InstructionBox>::new().into();
+        let metadata = Metadata::default();
+        let lane = TransactionLane::for_executable(&instructions);
+        let header = Section::Header(TransactionHeader {
+            chain: chain.clone(),
+            authority: authority.clone(),
+            creation_time_ms,
+            lane,
+            time_to_live_ms: None,
+            nonce: None,
+            gas_limit: None,
+            gas_price_tolerance: None,
+        });
+
         Self {
             payload: TransactionPayload {
                 chain,
                 authority,
                 creation_time_ms,
-                nonce: None,
+                commitments: TxCommitments::compute(header, &instructions, &metadata, &[]),
+                lane,
+                instructions,
+                metadata: Some(metadata),
+                memo: None,
                 time_to_live_ms: None,
-                instructions: Vec::<InstructionBox>::new().into(),
-                metadata: Metadata::default(),
+                nonce: None,
+                gas_limit: None,
+                gas_price_tolerance: None,
             },
+            multisig_signatures: Vec::new(),
         }
     }
 
@@ -517,6 +1339,31 @@ impl TransactionBuilder {
 }
 
 impl TransactionBuilder {
+    /// Recompute [`TransactionPayload::commitments`] from the current header fields and
+    /// sections.
+    ///
+    /// Must be called after any change to the header fields (chain, authority, creation time,
+    /// lane, time-to-live, nonce, gas limit, gas price tolerance) or the instructions,
+    /// metadata, or memo sections, since all of it is what the authenticator ends up signing
+    /// over.
+    fn recompute_commitments(&mut self) {
+        let metadata = self.payload.metadata.clone().unwrap_or_default();
+        let memo = self.payload.memo.clone().unwrap_or_default();
+        self.payload.lane = TransactionLane::for_executable(&self.payload.instructions);
+        let header = Section::Header(TransactionHeader {
+            chain: self.payload.chain.clone(),
+            authority: self.payload.authority.clone(),
+            creation_time_ms: self.payload.creation_time_ms,
+            lane: self.payload.lane,
+            time_to_live_ms: self.payload.time_to_live_ms,
+            nonce: self.payload.nonce,
+            gas_limit: self.payload.gas_limit,
+            gas_price_tolerance: self.payload.gas_price_tolerance,
+        });
+        self.payload.commitments =
+            TxCommitments::compute(header, &self.payload.instructions, &metadata, &memo);
+    }
+
     /// Set instructions for this transaction
     pub fn with_instructions<T: Instruction>(
         mut self,
@@ -527,30 +1374,79 @@ impl TransactionBuilder {
             .map(Into::into)
             .collect::<Vec<InstructionBox>>()
             .into();
+        self.recompute_commitments();
         self
     }
 
     /// Add wasm to this transaction
     pub fn with_wasm(mut self, wasm: WasmSmartContract) -> Self {
         self.payload.instructions = wasm.into();
+        self.recompute_commitments();
+        self
+    }
+
+    /// Add a reference to a previously-uploaded wasm smart contract to this transaction,
+    /// instead of inlining its bytes.
+    pub fn with_wasm_ref(mut self, wasm_hash: HashOf<WasmSmartContract>) -> Self {
+        self.payload.instructions = Executable::WasmById(wasm_hash);
+        self.recompute_commitments();
         self
     }
 
     /// Set executable for this transaction
     pub fn with_executable(mut self, executable: Executable) -> Self {
         self.payload.instructions = executable;
+        self.recompute_commitments();
         self
     }
 
     /// Adds metadata to this transaction
     pub fn with_metadata(mut self, metadata: Metadata) -> Self {
-        self.payload.metadata = metadata;
+        self.payload.metadata = Some(metadata);
+        self.recompute_commitments();
+        self
+    }
+
+    /// Attaches opaque memo bytes to this transaction, as a section separate from metadata
+    /// and instructions.
+    pub fn with_memo(mut self, memo: Vec<u8>) -> Self {
+        self.payload.memo = Some(memo);
+        self.recompute_commitments();
+        self
+    }
+
+    /// Make this transaction an atomic batch of the given, individually-signed inner
+    /// transactions: either every item executes, or the whole transaction is rejected.
+    pub fn batch(mut self, items: impl IntoIterator<Item = BatchTransaction>) -> Self {
+        self.payload.instructions = Executable::Batch(items.into_iter().collect());
+        self.recompute_commitments();
         self
     }
 
+    /// Lane the transaction currently under construction is classified under, derived from its
+    /// [`Executable`]
+    pub fn lane(&self) -> TransactionLane {
+        self.payload.lane
+    }
+
     /// Set nonce for this transaction
     pub fn set_nonce(&mut self, nonce: NonZeroU32) -> &mut Self {
         self.payload.nonce = Some(nonce);
+        self.recompute_commitments();
+        self
+    }
+
+    /// Set the upper bound on gas this transaction's execution may consume
+    pub fn set_gas_limit(&mut self, gas_limit: NonZeroU64) -> &mut Self {
+        self.payload.gas_limit = Some(gas_limit);
+        self.recompute_commitments();
+        self
+    }
+
+    /// Set the highest gas price the signer is willing to pay for this transaction
+    pub fn set_gas_price_tolerance(&mut self, gas_price_tolerance: NonZeroU64) -> &mut Self {
+        self.payload.gas_price_tolerance = Some(gas_price_tolerance);
+        self.recompute_commitments();
         self
     }
 
@@ -568,6 +1464,7 @@ impl TransactionBuilder {
             Some(NonZeroU64::new(ttl).expect("Can't be 0"))
         };
 
+        self.recompute_commitments();
         self
     }
 
@@ -575,13 +1472,17 @@ impl TransactionBuilder {
     pub fn set_creation_time(&mut self, value: Duration) -> &mut Self {
         self.payload.creation_time_ms = u64::try_from(value.as_millis())
             .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+        self.recompute_commitments();
         self
     }
 
     /// Sign transaction with provided key pair.
     #[must_use]
     pub fn sign(self, private_key: &iroha_crypto::PrivateKey) -> SignedTransaction {
-        let signature = TransactionSignature(SignatureOf::new(private_key, &self.payload));
+        let signature = TransactionAuthenticator::Single(TransactionSignature(SignatureOf::new(
+            private_key,
+            &self.payload.commitments,
+        )));
 
         SignedTransactionV1 {
             signature,
@@ -589,6 +1490,111 @@ impl TransactionBuilder {
         }
         .into()
     }
+
+    /// Append a co-signer's signature to an in-progress multisig authenticator, without
+    /// producing a finished [`SignedTransaction`].
+    ///
+    /// `index` identifies the signer's position in the authority account's ordered set of
+    /// public keys. Call this once per co-signer, then finish with [`Self::finish_multisig`].
+    #[must_use]
+    pub fn sign_with(mut self, index: u8, private_key: &iroha_crypto::PrivateKey) -> Self {
+        self.multisig_signatures
+            .push((index, SignatureOf::new(private_key, &self.payload.commitments)));
+        self
+    }
+
+    /// Finish a multisig authenticator built up via [`Self::sign_with`], requiring at least
+    /// `threshold` valid signatures to authorize the transaction.
+    ///
+    /// Signatures are sorted by signer index so that the encoding, and thus the transaction
+    /// hash, is stable regardless of the order they were collected in.
+    #[must_use]
+    pub fn finish_multisig(mut self, threshold: NonZeroU8) -> SignedTransaction {
+        self.multisig_signatures.sort_by_key(|(index, _)| *index);
+
+        let signature = TransactionAuthenticator::MultiSignature {
+            signatures: self.multisig_signatures,
+            threshold,
+        };
+
+        SignedTransactionV1 {
+            signature,
+            payload: self.payload,
+        }
+        .into()
+    }
+}
+
+/// Builder for one inner transaction of an [`Executable::Batch`], signed independently by its
+/// own authority via the same single-/multi-signature path as a top-level [`TransactionBuilder`]
+/// before being folded into the outer batch.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct BatchTransactionBuilder {
+    authority: AccountId,
+    executable: Executable,
+    commitments: TxCommitments,
+    multisig_signatures: Vec<(u8, SignatureOf<TxCommitments>)>,
+}
+
+impl BatchTransactionBuilder {
+    /// Construct [`Self`] for the given authority and executable.
+    pub fn new(authority: AccountId, executable: Executable) -> Self {
+        let header = Section::BatchHeader(BatchTransactionHeader {
+            authority: authority.clone(),
+        });
+        let commitments = TxCommitments::compute(header, &executable, &Metadata::default(), &[]);
+        Self {
+            authority,
+            executable,
+            commitments,
+            multisig_signatures: Vec::new(),
+        }
+    }
+
+    /// Sign this inner transaction with a single key pair, finishing it.
+    #[must_use]
+    pub fn sign(self, private_key: &iroha_crypto::PrivateKey) -> BatchTransaction {
+        let signature = TransactionAuthenticator::Single(TransactionSignature(SignatureOf::new(
+            private_key,
+            &self.commitments,
+        )));
+
+        BatchTransaction {
+            authority: self.authority,
+            executable: self.executable,
+            commitments: self.commitments,
+            signature,
+        }
+    }
+
+    /// Append a co-signer's signature to an in-progress multisig authenticator for this inner
+    /// transaction, without finishing it. See [`TransactionBuilder::sign_with`].
+    #[must_use]
+    pub fn sign_with(mut self, index: u8, private_key: &iroha_crypto::PrivateKey) -> Self {
+        self.multisig_signatures
+            .push((index, SignatureOf::new(private_key, &self.commitments)));
+        self
+    }
+
+    /// Finish a multisig authenticator built up via [`Self::sign_with`]. See
+    /// [`TransactionBuilder::finish_multisig`].
+    #[must_use]
+    pub fn finish_multisig(mut self, threshold: NonZeroU8) -> BatchTransaction {
+        self.multisig_signatures.sort_by_key(|(index, _)| *index);
+
+        let signature = TransactionAuthenticator::MultiSignature {
+            signatures: self.multisig_signatures,
+            threshold,
+        };
+
+        BatchTransaction {
+            authority: self.authority,
+            executable: self.executable,
+            commitments: self.commitments,
+            signature,
+        }
+    }
 }
 
 impl TransactionEntrypoint {
@@ -731,10 +1737,102 @@ pub mod error {
             /// Instruction for which execution failed
             #[getset(get = "pub")]
             pub instruction: InstructionBox,
+            /// Zero-based position of [`Self::instruction`] within the sequence it was executed
+            /// as part of — a transaction's top-level instructions, or a `DataTriggerStep`'s —
+            /// so a rejection can be mapped back to the exact call in a submitted batch.
+            #[getset(get = "pub")]
+            pub index: usize,
+            /// Total number of instructions in that sequence.
+            #[getset(get = "pub")]
+            pub total: usize,
             /// Error which happened during execution
             pub reason: String,
         }
 
+        /// Failure of one inner transaction within an `Executable::Batch`, causing the whole
+        /// batch transaction to be rejected.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[ffi_type]
+        pub struct BatchItemFailure {
+            /// Index of the failing inner transaction within the batch.
+            #[getset(get = "pub")]
+            pub index: usize,
+            /// The underlying rejection reason for that inner transaction.
+            pub reason: Box<TransactionRejectionReason>,
+        }
+
+        /// Structured reason the host `WebAssembly` engine trapped, mapped from the engine's own
+        /// trap kind so clients can distinguish failure modes without parsing [`WasmExecutionFail::reason`].
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub enum TrapCode {
+            /// Call stack exhausted
+            StackOverflow,
+            /// Out-of-bounds access to linear memory
+            MemoryOutOfBounds,
+            /// Out-of-bounds access to a table
+            TableOutOfBounds,
+            /// `call_indirect` called a function of the wrong type
+            IndirectCallTypeMismatch,
+            /// Arithmetic overflow, e.g. signed division overflow
+            IntegerOverflow,
+            /// Division, or remainder, by zero
+            IntegerDivisionByZero,
+            /// An `unreachable` instruction was executed
+            UnreachableReached,
+            /// Called function or its signature could not be resolved
+            BadSignature,
+        }
+
+        /// One call frame of a captured [`WasmExecutionFail::backtrace`], innermost frame first.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub struct FrameInfo {
+            /// Name of the wasm module the frame's function belongs to.
+            pub module_name: String,
+            /// Index of the function within its module.
+            pub function_index: u32,
+            /// Function symbol, demangled with `rustc-demangle` at capture time, if the
+            /// compiled contract carried a name section for it.
+            pub symbol: Option<String>,
+        }
+
         /// Transaction was rejected because execution of `WebAssembly` binary failed
         #[derive(
             Debug,
@@ -751,13 +1849,20 @@ pub mod error {
             IntoSchema,
         )]
         #[display(fmt = "Failed to execute wasm binary: {reason}")]
-        #[serde(transparent)]
-        #[repr(transparent)]
-        // SAFETY: `WasmExecutionFail` has no trap representation in `String`
-        #[ffi_type(unsafe {robust})]
+        #[ffi_type]
         pub struct WasmExecutionFail {
             /// Error which happened during execution
             pub reason: String,
+            /// Structured trap code mapped from the host engine's trap, if known.
+            pub trap: Option<TrapCode>,
+            /// Call-frame backtrace captured at the point of the trap, innermost frame first.
+            /// Only ever populated by a [`feature = "std"`] host, since capturing it needs the
+            /// engine's runtime backtrace support; a `no_std` peer can still decode and relay it.
+            pub backtrace: Option<Vec<FrameInfo>>,
+            /// Formatted Rust panic message (payload plus file/line) recovered from the guest's
+            /// linear memory, if the contract was built with `iroha_data_model`'s
+            /// `wasm-panic-handler` feature and panicked rather than trapping some other way.
+            pub panic_message: Option<String>,
         }
 
         /// Possible reasons for trigger-specific execution failure.
@@ -776,10 +1881,157 @@ pub mod error {
             IntoSchema,
         )]
         #[ffi_type]
-        #[repr(u32)]
         pub enum TriggerExecutionFail {
             /// Exceeded maximum depth for chained data triggers.
             MaxDepthExceeded,
+            /// A data trigger somewhere in the chain failed.
+            TriggerFailed {
+                /// Index and id of each data trigger step on the path from the entrypoint down
+                /// to the step that actually failed.
+                trigger_path: Vec<(usize, TriggerId)>,
+                /// The original error, exactly as produced by the failing step — not re-wrapped
+                /// at each hop on the way back up.
+                #[cfg_attr(feature = "std", source)]
+                cause: Box<TransactionRejectionReason>,
+            },
+        }
+
+        /// Reason a [`super::super::TransactionAuthenticator`] failed to verify.
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[ignore_extra_doc_attributes]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        pub enum SignatureVerificationError {
+            /// Signer index {index} is repeated among the multi-signature entries
+            DuplicateSignerIndex {
+                /// The repeated signer index
+                index: u8,
+            },
+            /// Signer index {index} has no corresponding key among the {key_count} authorized keys
+            SignerIndexOutOfRange {
+                /// The out-of-range signer index
+                index: u8,
+                /// Number of keys authorized for the authority account
+                key_count: usize,
+            },
+            /// Only {valid} of the required {threshold} signatures were valid
+            ThresholdNotMet {
+                /// Number of signatures that verified successfully
+                valid: u8,
+                /// Minimum number of valid signatures required
+                threshold: u8,
+            },
+            /// Signature does not match the payload
+            InvalidSignature(#[cfg_attr(feature = "std", source)] iroha_crypto::Error),
+            /// The {section} section's hash does not match its commitment
+            CommitmentMismatch {
+                /// Name of the section whose hash didn't match
+                section: String,
+            },
+            /// A multi-signature authenticator requires the authority's ordered set of public
+            /// keys; use `verify_authenticator` instead of `verify_signature`
+            MultiSignatureUnsupported,
+        }
+
+        /// Reason a [`super::super::TransactionInfoWithProof`] failed to verify.
+        #[derive(
+            Debug,
+            displaydoc::Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[cfg_attr(feature = "std", derive(thiserror::Error))]
+        pub enum AccumulatorProofError {
+            /// Recomputed accumulator root does not match the expected root
+            RootMismatch,
+        }
+
+        /// Which resource budget [`TransactionRejectionReason::LimitExceeded`] was rejected for
+        /// exhausting.
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub enum ResourceLimitKind {
+            /// The host engine's fuel/instruction budget for guest `WebAssembly` execution.
+            Fuel,
+            /// Linear memory grew past the number of pages the host allows.
+            MemoryPages,
+            /// The guest's call stack grew past the configured recursion depth.
+            CallStackDepth,
+            /// The transaction's own instruction-count budget, as tracked by
+            /// [`TransactionLimitError`].
+            InstructionCount,
+        }
+
+        /// Coarse category of [`TransactionRejectionReason`], for filtering failed transactions
+        /// by kind (e.g. from [`crate::query::dsl::predicates::TransactionResultPredicateAtom::ErrorMatches`])
+        /// without matching on the full reason and its payload.
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub enum RejectionReasonKind {
+            /// See [`TransactionRejectionReason::AccountDoesNotExist`]
+            AccountDoesNotExist,
+            /// See [`TransactionRejectionReason::LimitCheck`]
+            LimitCheck,
+            /// See [`TransactionRejectionReason::Validation`]
+            Validation,
+            /// See [`TransactionRejectionReason::InstructionExecution`]
+            InstructionExecution,
+            /// See [`TransactionRejectionReason::WasmExecution`]
+            WasmExecution,
+            /// See [`TransactionRejectionReason::TriggerExecution`]
+            TriggerExecution,
+            /// See [`TransactionRejectionReason::BatchItemFailed`]
+            BatchItemFailed,
+            /// See [`TransactionRejectionReason::GasPriceToleranceTooLow`]
+            GasPriceToleranceTooLow,
+            /// See [`TransactionRejectionReason::InvalidTransactionLane`]
+            InvalidTransactionLane,
+            /// See [`TransactionRejectionReason::LimitExceeded`]
+            LimitExceeded,
         }
 
         /// The reason for rejecting transaction which happened because of transaction.
@@ -827,6 +2079,36 @@ pub mod error {
             WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
             /// Execution of a time trigger or an invoked data trigger failed.
             TriggerExecution(#[cfg_attr(feature = "std", source)] TriggerExecutionFail),
+            /// One inner transaction of a transaction batch failed
+            BatchItemFailed(#[cfg_attr(feature = "std", source)] Box<BatchItemFailure>),
+            /// The signer's gas price tolerance is below the current network gas price
+            GasPriceToleranceTooLow {
+                /// Gas price the signer offered to pay, at most
+                offered: u64,
+                /// Current network gas price, which the signer's tolerance fell short of
+                required: u64,
+            },
+            /// The transaction's declared lane ({declared}) does not match its executable
+            /// content, or its content exceeds that lane's configured size/instruction budget
+            InvalidTransactionLane {
+                /// The lane declared on the transaction
+                declared: TransactionLane,
+            },
+            /// Exceeded the {limit:?} budget: consumed {consumed} of {allowed} allowed
+            ///
+            /// Distinct from a genuine execution bug, so clients can retry with a higher budget
+            /// instead of treating the transaction as buggy. A [`TriggerExecutionFail`] whose
+            /// `cause` is this variant lets the executor short-circuit a `DataTriggerSequence`
+            /// as soon as the cumulative budget across its steps runs out, rather than running
+            /// the remaining steps only to have them fail the same way.
+            LimitExceeded {
+                /// Which budget was exhausted
+                limit: ResourceLimitKind,
+                /// How much of the budget had been consumed when it was hit
+                consumed: u64,
+                /// The budget that was configured
+                allowed: u64,
+            },
         }
     }
 
@@ -851,8 +2133,21 @@ pub mod error {
             };
             write!(
                 f,
-                "Failed to execute instruction of type {}: {}",
-                kind, self.reason
+                "Failed to execute instruction #{} of {} of type {}: {}",
+                self.index + 1,
+                self.total,
+                kind,
+                self.reason
+            )
+        }
+    }
+
+    impl Display for BatchItemFailure {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(
+                f,
+                "Inner transaction #{} of batch failed: {}",
+                self.index, self.reason
             )
         }
     }
@@ -869,22 +2164,143 @@ pub mod error {
     #[cfg(feature = "std")]
     impl std::error::Error for TriggerExecutionFail {}
 
+    #[cfg(feature = "std")]
+    impl std::error::Error for BatchItemFailure {}
+
+    impl TransactionRejectionReason {
+        /// The coarse [`RejectionReasonKind`] of this reason, ignoring its payload.
+        pub fn kind(&self) -> RejectionReasonKind {
+            match self {
+                Self::AccountDoesNotExist(_) => RejectionReasonKind::AccountDoesNotExist,
+                Self::LimitCheck(_) => RejectionReasonKind::LimitCheck,
+                Self::Validation(_) => RejectionReasonKind::Validation,
+                Self::InstructionExecution(_) => RejectionReasonKind::InstructionExecution,
+                Self::WasmExecution(_) => RejectionReasonKind::WasmExecution,
+                Self::TriggerExecution(_) => RejectionReasonKind::TriggerExecution,
+                Self::BatchItemFailed(_) => RejectionReasonKind::BatchItemFailed,
+                Self::GasPriceToleranceTooLow { .. } => {
+                    RejectionReasonKind::GasPriceToleranceTooLow
+                }
+                Self::InvalidTransactionLane { .. } => RejectionReasonKind::InvalidTransactionLane,
+                Self::LimitExceeded { .. } => RejectionReasonKind::LimitExceeded,
+            }
+        }
+    }
+
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this module.
 
         pub use super::{
-            InstructionExecutionFail, TransactionRejectionReason, TriggerExecutionFail,
-            WasmExecutionFail,
+            AccumulatorProofError, BatchItemFailure, FrameInfo, InstructionExecutionFail,
+            RejectionReasonKind, ResourceLimitKind, SignatureVerificationError,
+            TransactionRejectionReason, TrapCode, TriggerExecutionFail, WasmExecutionFail,
         };
     }
 }
 
+/// Support for recovering a Rust panic message from a `no_std` smart contract that panicked
+/// inside the sandbox, rather than flattening it into an opaque
+/// [`error::TrapCode::UnreachableReached`].
+///
+/// Building a contract against this crate with the `wasm-panic-handler` feature (only meaningful
+/// without `std`) installs a [`core::panic::PanicInfo`] handler that formats the panic payload
+/// and its `file:line` into [`PANIC_MESSAGE_BUFFER`] before trapping the guest with
+/// `unreachable`, the same instruction it would have trapped with anyway. The host then recovers
+/// the message with [`panic_message_bytes`] by reading the guest's linear memory at the address
+/// the contract exports the buffer under, and attaches it via
+/// [`error::WasmExecutionFail::with_panic_message`].
+pub mod wasm_panic {
+    /// Bytes reserved for the formatted panic message, not counting the 4-byte length prefix.
+    /// Longer messages are truncated to fit rather than growing the buffer, so the handler never
+    /// needs an allocator.
+    pub const MESSAGE_CAPACITY: usize = 1024;
+
+    /// Fixed linear-memory region the guest panic handler writes into: a little-endian `u32`
+    /// length prefix followed by up to [`MESSAGE_CAPACITY`] bytes of UTF-8. The contract must
+    /// export its address (e.g. as `_panic_message_ptr`) for the host to read after a trap.
+    #[cfg(all(not(feature = "std"), feature = "wasm-panic-handler"))]
+    #[no_mangle]
+    static mut PANIC_MESSAGE_BUFFER: [u8; MESSAGE_CAPACITY + 4] = [0; MESSAGE_CAPACITY + 4];
+
+    /// The largest prefix of `s`, no longer than `max_len` bytes, that ends on a UTF-8 char
+    /// boundary. Cutting mid-codepoint would make `core::str::from_utf8` reject the whole
+    /// truncated message on the host side, not just the cut tail.
+    pub(crate) fn truncate_at_char_boundary(s: &str, max_len: usize) -> usize {
+        let take = max_len.min(s.len());
+        (0..=take).rev().find(|&i| s.is_char_boundary(i)).unwrap_or(0)
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "wasm-panic-handler"))]
+    struct BufferWriter {
+        len: usize,
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "wasm-panic-handler"))]
+    impl core::fmt::Write for BufferWriter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            // SAFETY: the guest is single-threaded, this is the only writer of the buffer, and
+            // the host only reads it back after the guest has already trapped.
+            let buf = unsafe { &mut PANIC_MESSAGE_BUFFER[4..] };
+            let remaining = buf.len() - self.len;
+            let take = truncate_at_char_boundary(s, remaining);
+            buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+            self.len += take;
+            Ok(())
+        }
+    }
+
+    /// Installed as the guest's `#[panic_handler]`: formats `info` as
+    /// `"<payload> at <file>:<line>"` into [`PANIC_MESSAGE_BUFFER`], then traps with the same
+    /// `unreachable` instruction the guest would have hit without this handler.
+    #[cfg(all(not(feature = "std"), feature = "wasm-panic-handler"))]
+    #[panic_handler]
+    fn panic(info: &core::panic::PanicInfo) -> ! {
+        use core::fmt::Write;
+
+        let mut writer = BufferWriter { len: 0 };
+        let _ = match info.location() {
+            Some(location) => write!(
+                writer,
+                "{} at {}:{}",
+                info.message(),
+                location.file(),
+                location.line()
+            ),
+            None => write!(writer, "{}", info.message()),
+        };
+
+        // SAFETY: `writer.len` never exceeds `MESSAGE_CAPACITY`, so this always lands inside
+        // `PANIC_MESSAGE_BUFFER`.
+        unsafe {
+            PANIC_MESSAGE_BUFFER[..4].copy_from_slice(&(writer.len as u32).to_le_bytes());
+        }
+
+        core::arch::wasm32::unreachable()
+    }
+
+    /// Recover a panic message the guest's handler wrote into its linear memory, given the
+    /// buffer's address as exposed by the contract. Returns [`None`] if the guest never
+    /// panicked (the length prefix is still zero) or `buffer_ptr` is out of bounds.
+    pub fn panic_message_bytes(memory: &[u8], buffer_ptr: usize) -> Option<&str> {
+        let len_bytes: [u8; 4] = memory.get(buffer_ptr..buffer_ptr + 4)?.try_into().ok()?;
+        let len = u32::from_le_bytes(len_bytes) as usize;
+        if len == 0 {
+            return None;
+        }
+        let bytes = memory.get(buffer_ptr + 4..buffer_ptr + 4 + len)?;
+        core::str::from_utf8(bytes).ok()
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
-        error::prelude::*, DataTriggerSequence, DataTriggerStep, Executable, ExecutionStep,
-        SignedTransaction, TimeTriggerEntrypoint, TransactionBuilder, TransactionEntrypoint,
-        TransactionResult, TransactionResultInner, WasmSmartContract,
+        error::prelude::*, AccumulatorBuilder, AccumulatorProof, BatchTransaction,
+        BatchTransactionBuilder, DataTriggerGroup, DataTriggerSequence, DataTriggerStep,
+        Executable, ExecutionStep, Section, SignedTransaction, TimeTriggerEntrypoint,
+        TransactionAuthenticator, TransactionBuilder, TransactionEntrypoint,
+        TransactionInfoWithProof, TransactionLane, TransactionResult, TransactionResultInner,
+        TxCommitments, WasmSmartContract,
     };
 }
 
@@ -900,4 +2316,315 @@ mod tests {
         let contract = WasmSmartContract::from_compiled(vec![0, 1, 2, 3, 4]);
         assert_eq!(format!("{contract:?}"), "WASM binary(len = 5)");
     }
+
+    #[test]
+    fn panic_message_bytes_decodes_length_prefixed_utf8() {
+        let message = b"assertion failed: balance >= amount at lib.rs:42";
+        let mut memory = vec![0u8; 4 + message.len()];
+        memory[..4].copy_from_slice(&(message.len() as u32).to_le_bytes());
+        memory[4..].copy_from_slice(message);
+
+        assert_eq!(
+            wasm_panic::panic_message_bytes(&memory, 0),
+            Some("assertion failed: balance >= amount at lib.rs:42")
+        );
+    }
+
+    #[test]
+    fn panic_message_bytes_is_none_when_guest_never_panicked() {
+        let memory = vec![0u8; 8];
+        assert_eq!(wasm_panic::panic_message_bytes(&memory, 0), None);
+    }
+
+    #[test]
+    fn truncate_at_char_boundary_keeps_whole_prefix_that_already_fits() {
+        assert_eq!(wasm_panic::truncate_at_char_boundary("hello", 10), 5);
+    }
+
+    #[test]
+    fn truncate_at_char_boundary_backs_off_a_split_multi_byte_char() {
+        // "é" is 2 bytes (0xC3 0xA9); a max_len landing inside it must back off to 1.
+        let s = "aé";
+        assert_eq!(wasm_panic::truncate_at_char_boundary(s, 2), 1);
+        assert_eq!(&s[..wasm_panic::truncate_at_char_boundary(s, 2)], "a");
+    }
+
+    #[test]
+    fn truncate_at_char_boundary_handles_max_len_zero() {
+        assert_eq!(wasm_panic::truncate_at_char_boundary("é", 0), 0);
+    }
+
+    fn entrypoint_hash(seed: u8) -> HashOf<TransactionEntrypoint> {
+        HashOf::from_untyped_unchecked(HashOf::<[u8; 1]>::new(&[seed]).into())
+    }
+
+    #[test]
+    fn accumulator_builder_rejects_empty_leaves() {
+        assert!(AccumulatorBuilder::new(&[]).is_none());
+    }
+
+    #[test]
+    fn accumulator_builder_single_leaf_proves_against_its_own_hash() {
+        let leaf = entrypoint_hash(0);
+        let accumulator = AccumulatorBuilder::new(&[leaf]).expect("one leaf");
+
+        assert_eq!(accumulator.root(), leaf);
+        let proof = accumulator.proof(0).expect("leaf 0 exists");
+        assert!(proof.0.is_empty());
+    }
+
+    #[test]
+    fn accumulator_builder_roundtrips_every_leaf_for_odd_and_even_sizes() {
+        for leaf_count in [2usize, 3, 4, 5, 7] {
+            let leaves: Vec<_> = (0..leaf_count as u8).map(entrypoint_hash).collect();
+            let accumulator = AccumulatorBuilder::new(&leaves).expect("non-empty leaves");
+            let root = accumulator.root();
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = accumulator.proof(index).expect("leaf exists");
+                let info = TransactionInfoWithProof {
+                    transaction_hash: *leaf,
+                    result_hash: TransactionResult(Ok(DataTriggerSequence::new())).hash(),
+                    proof,
+                    leaf_index: index as u64,
+                };
+                assert_eq!(info.verify(root), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn accumulator_builder_proof_is_none_out_of_range() {
+        let leaves = [entrypoint_hash(0), entrypoint_hash(1)];
+        let accumulator = AccumulatorBuilder::new(&leaves).expect("non-empty leaves");
+        assert!(accumulator.proof(2).is_none());
+    }
+
+    /// A fresh throwaway account and its matching key pair, for tests that need *an* authority
+    /// and signer without caring which one.
+    fn test_account() -> (AccountId, PublicKey, iroha_crypto::PrivateKey) {
+        let (public_key, private_key) = iroha_crypto::KeyPair::random().into_parts();
+        let authority = format!("{public_key}@wonderland")
+            .parse()
+            .expect("well-formed account id");
+        (authority, public_key, private_key)
+    }
+
+    /// Builds a signed [`BatchTransaction`] wrapping `executable`, using a fresh throwaway key
+    /// pair; `for_executable` only looks at the structure of [`Executable`], not at the
+    /// authority or signature, so the key pair's only job is to make this type-check.
+    fn batch_item(executable: Executable) -> BatchTransaction {
+        let (authority, _, private_key) = test_account();
+        BatchTransactionBuilder::new(authority, executable).sign(&private_key)
+    }
+
+    #[test]
+    fn for_executable_classifies_wasm_hidden_inside_a_batch_item_as_install_wasm() {
+        let hidden_wasm = Executable::Wasm(WasmSmartContract::from_compiled(vec![0, 1, 2, 3]));
+        let batch = Executable::Batch(vec![batch_item(hidden_wasm)]);
+
+        assert_eq!(TransactionLane::for_executable(&batch), TransactionLane::InstallWasm);
+    }
+
+    #[test]
+    fn for_executable_falls_back_to_install_wasm_past_the_batch_nesting_limit() {
+        // Each level on its own would classify as `Wasm` (the innermost `WasmById`); nested
+        // past `MAX_BATCH_NESTING_DEPTH`, `for_executable` must give up and report the most
+        // restrictive lane rather than keep recursing.
+        let mut executable = Executable::WasmById(HashOf::from_untyped_unchecked(
+            HashOf::<[u8; 1]>::new(&[0]).into(),
+        ));
+        for _ in 0..(TransactionLane::MAX_BATCH_NESTING_DEPTH + 4) {
+            executable = Executable::Batch(vec![batch_item(executable)]);
+        }
+
+        assert_eq!(
+            TransactionLane::for_executable(&executable),
+            TransactionLane::InstallWasm
+        );
+    }
+
+    fn test_chain() -> ChainId {
+        ChainId::from("0")
+    }
+
+    fn signed_transaction() -> (SignedTransaction, PublicKey) {
+        let (authority, public_key, private_key) = test_account();
+        let tx = TransactionBuilder::new(test_chain(), authority).sign(&private_key);
+        (tx, public_key)
+    }
+
+    #[test]
+    fn verify_signature_round_trips_for_a_freshly_signed_transaction() {
+        let (tx, _) = signed_transaction();
+        assert_eq!(tx.verify_signature(), Ok(()));
+    }
+
+    #[test]
+    fn verify_authenticator_round_trips_against_the_authorized_key() {
+        let (tx, public_key) = signed_transaction();
+        assert_eq!(tx.verify_authenticator(&[public_key]), Ok(()));
+    }
+
+    #[test]
+    fn verify_signature_rejects_tampered_instructions_even_though_signature_is_untouched() {
+        let (mut tx, _) = signed_transaction();
+        let SignedTransaction::V1(inner) = &mut tx;
+        inner.payload.instructions =
+            Executable::Wasm(WasmSmartContract::from_compiled(vec![0xDE, 0xAD]));
+
+        assert_eq!(
+            tx.verify_signature(),
+            Err(error::SignatureVerificationError::CommitmentMismatch {
+                section: "executable".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn verify_signature_still_succeeds_after_dropping_the_metadata_section() {
+        let (authority, _, private_key) = test_account();
+        let mut tx = TransactionBuilder::new(test_chain(), authority)
+            .with_metadata(Metadata::default())
+            .sign(&private_key);
+
+        tx.drop_metadata_section();
+
+        assert_eq!(tx.verify_signature(), Ok(()));
+        assert_eq!(tx.metadata(), None);
+    }
+
+    #[test]
+    fn verify_signature_still_succeeds_after_dropping_the_memo_section() {
+        let (authority, _, private_key) = test_account();
+        let mut tx = TransactionBuilder::new(test_chain(), authority)
+            .with_memo(vec![1, 2, 3])
+            .sign(&private_key);
+
+        tx.drop_memo_section();
+
+        assert_eq!(tx.verify_signature(), Ok(()));
+        assert_eq!(tx.memo(), None);
+    }
+
+    #[test]
+    fn verify_authenticator_multisig_succeeds_once_threshold_is_met() {
+        let (authority, _, _) = test_account();
+        let (_, public_key_0, signer_0) = test_account();
+        let (_, public_key_1, signer_1) = test_account();
+
+        let tx = TransactionBuilder::new(test_chain(), authority)
+            .sign_with(0, &signer_0)
+            .sign_with(1, &signer_1)
+            .finish_multisig(NonZeroU8::new(2).expect("nonzero"));
+
+        assert_eq!(
+            tx.verify_authenticator(&[public_key_0, public_key_1]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_authenticator_multisig_reports_threshold_not_met() {
+        let (authority, _, _) = test_account();
+        let (_, public_key_0, signer_0) = test_account();
+        let (_, other_public_key, _) = test_account();
+
+        let tx = TransactionBuilder::new(test_chain(), authority)
+            .sign_with(0, &signer_0)
+            .finish_multisig(NonZeroU8::new(2).expect("nonzero"));
+
+        assert_eq!(
+            tx.verify_authenticator(&[public_key_0, other_public_key]),
+            Err(error::SignatureVerificationError::ThresholdNotMet {
+                valid: 1,
+                threshold: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn verify_authenticator_multisig_reports_duplicate_signer_index() {
+        let (authority, _, _) = test_account();
+        let (_, public_key_0, signer_0) = test_account();
+        let (_, _, signer_0_again) = test_account();
+
+        let tx = TransactionBuilder::new(test_chain(), authority)
+            .sign_with(0, &signer_0)
+            .sign_with(0, &signer_0_again)
+            .finish_multisig(NonZeroU8::new(1).expect("nonzero"));
+
+        assert_eq!(
+            tx.verify_authenticator(&[public_key_0]),
+            Err(error::SignatureVerificationError::DuplicateSignerIndex { index: 0 })
+        );
+    }
+
+    #[test]
+    fn verify_authenticator_multisig_reports_signer_index_out_of_range() {
+        let (authority, _, _) = test_account();
+        let (_, public_key_0, signer_0) = test_account();
+
+        let tx = TransactionBuilder::new(test_chain(), authority)
+            .sign_with(5, &signer_0)
+            .finish_multisig(NonZeroU8::new(1).expect("nonzero"));
+
+        assert_eq!(
+            tx.verify_authenticator(&[public_key_0]),
+            Err(error::SignatureVerificationError::SignerIndexOutOfRange {
+                index: 5,
+                key_count: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn batch_transaction_verify_round_trips_against_its_own_authority() {
+        let (authority, public_key, private_key) = test_account();
+        let item = BatchTransactionBuilder::new(
+            authority,
+            Executable::Instructions(Vec::<InstructionBox>::new().into()),
+        )
+        .sign(&private_key);
+
+        assert_eq!(item.verify(&[public_key]), Ok(()));
+    }
+
+    #[test]
+    fn batch_transaction_verify_rejects_tampered_executable() {
+        let (authority, public_key, private_key) = test_account();
+        let mut item = BatchTransactionBuilder::new(
+            authority,
+            Executable::Instructions(Vec::<InstructionBox>::new().into()),
+        )
+        .sign(&private_key);
+        item.executable = Executable::Wasm(WasmSmartContract::from_compiled(vec![0xDE, 0xAD]));
+
+        assert_eq!(
+            item.verify(&[public_key]),
+            Err(error::SignatureVerificationError::CommitmentMismatch {
+                section: "executable".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn batch_round_trips_through_outer_transaction_and_each_inner_item() {
+        let (outer_authority, _, outer_private_key) = test_account();
+        let (item_authority, item_public_key, item_private_key) = test_account();
+
+        let item = BatchTransactionBuilder::new(
+            item_authority,
+            Executable::Instructions(Vec::<InstructionBox>::new().into()),
+        )
+        .sign(&item_private_key);
+
+        let tx = TransactionBuilder::new(test_chain(), outer_authority)
+            .batch(vec![item])
+            .sign(&outer_private_key);
+
+        let items = tx.inner_transactions().expect("this is a batch");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].verify(&[item_public_key]), Ok(()));
+    }
 }